@@ -0,0 +1,163 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{Direction, Food, GridPosition, Player, WallMode};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AiStrategy {
+    Greedy,
+    Bfs,
+}
+
+impl AiStrategy {
+    pub fn choose(
+        &self,
+        player: &Player,
+        foods: &[Food],
+        obstacles: &[GridPosition],
+        grid: (i16, i16),
+        wall_mode: WallMode,
+    ) -> Option<Direction> {
+        let area = Area {
+            player,
+            obstacles,
+            grid,
+            wall_mode,
+        };
+
+        match *self {
+            AiStrategy::Greedy => greedy(&area, foods),
+            AiStrategy::Bfs => bfs(&area, foods).or_else(|| roomiest(&area)),
+        }
+    }
+}
+
+struct Area<'a> {
+    player: &'a Player,
+    obstacles: &'a [GridPosition],
+    grid: (i16, i16),
+    wall_mode: WallMode,
+}
+
+impl Area<'_> {
+    fn step(&self, position: GridPosition, direction: Direction) -> Option<GridPosition> {
+        let next = position.moved(direction, self.grid, self.wall_mode)?;
+
+        if self.player.occupies(next) || self.obstacles.contains(&next) {
+            None
+        } else {
+            Some(next)
+        }
+    }
+
+    fn first_moves(&self) -> impl Iterator<Item = (Direction, GridPosition)> + '_ {
+        let head = self.player.head.position;
+        let reverse = self.player.heading().inverse();
+
+        Direction::ALL
+            .into_iter()
+            .filter(move |direction| *direction != reverse)
+            .filter_map(move |direction| Some((direction, self.step(head, direction)?)))
+    }
+
+    fn reachable_from(&self, start: GridPosition) -> usize {
+        let mut seen = HashSet::from([start]);
+        let mut frontier = VecDeque::from([start]);
+
+        while let Some(position) = frontier.pop_front() {
+            for direction in Direction::ALL {
+                if let Some(next) = self.step(position, direction) {
+                    if seen.insert(next) {
+                        frontier.push_back(next);
+                    }
+                }
+            }
+        }
+
+        seen.len()
+    }
+}
+
+fn greedy(area: &Area, foods: &[Food]) -> Option<Direction> {
+    let head = area.player.head.position;
+    let offset = |target: GridPosition| {
+        let (wrapped_x, wrapped_y) = head.toroidal_offset(target, area.grid);
+
+        (
+            if area.wall_mode.is_solid(Direction::Right) {
+                target.x - head.x
+            } else {
+                wrapped_x
+            },
+            if area.wall_mode.is_solid(Direction::Down) {
+                target.y - head.y
+            } else {
+                wrapped_y
+            },
+        )
+    };
+
+    let (dx, dy) = foods
+        .iter()
+        .map(|food| offset(food.position))
+        .min_by_key(|(dx, dy)| dx.abs() + dy.abs())?;
+
+    let horizontal = if dx < 0 {
+        Direction::Left
+    } else {
+        Direction::Right
+    };
+    let vertical = if dy < 0 {
+        Direction::Up
+    } else {
+        Direction::Down
+    };
+
+    let preferred = if dx.abs() >= dy.abs() {
+        [horizontal, vertical]
+    } else {
+        [vertical, horizontal]
+    };
+
+    let safe: Vec<Direction> = area.first_moves().map(|(direction, _)| direction).collect();
+
+    preferred
+        .into_iter()
+        .chain(Direction::ALL)
+        .find(|direction| safe.contains(direction))
+}
+
+fn bfs(area: &Area, foods: &[Food]) -> Option<Direction> {
+    let mut first_steps: HashMap<GridPosition, Direction> = HashMap::new();
+    let mut frontier = VecDeque::new();
+
+    for (direction, next) in area.first_moves() {
+        first_steps.insert(next, direction);
+        frontier.push_back(next);
+    }
+
+    while let Some(position) = frontier.pop_front() {
+        let first_step = first_steps[&position];
+
+        if foods.iter().any(|food| food.position == position) {
+            return Some(first_step);
+        }
+
+        for direction in Direction::ALL {
+            if let Some(next) = area.step(position, direction) {
+                if let Entry::Vacant(entry) = first_steps.entry(next) {
+                    entry.insert(first_step);
+                    frontier.push_back(next);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn roomiest(area: &Area) -> Option<Direction> {
+    area.first_moves()
+        .max_by_key(|(_, next)| area.reachable_from(*next))
+        .map(|(direction, _)| direction)
+}
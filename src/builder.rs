@@ -0,0 +1,82 @@
+use ggez::{Context, GameResult};
+
+use std::path::PathBuf;
+
+use crate::{
+    AiStrategy, Direction, GameConfig, GameState, GridPosition, ObstacleLayout, Theme, WallMode,
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct GameStateBuilder {
+    config: GameConfig,
+}
+
+impl GameStateBuilder {
+    pub fn new() -> Self {
+        GameStateBuilder::default()
+    }
+
+    pub fn from_config(config: GameConfig) -> Self {
+        GameStateBuilder { config }
+    }
+
+    pub fn grid(mut self, grid: (i16, i16)) -> Self {
+        self.config.grid = grid;
+        self
+    }
+
+    pub fn cell(mut self, cell: (i16, i16)) -> Self {
+        self.config.cell = cell;
+        self
+    }
+
+    pub fn speed(mut self, frames_per_second: f32) -> Self {
+        self.config.frames_per_second = frames_per_second;
+        self
+    }
+
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.config.theme = theme;
+        self
+    }
+
+    pub fn walls(mut self, wall_mode: WallMode) -> Self {
+        self.config.wall_mode = wall_mode;
+        self
+    }
+
+    pub fn obstacles(mut self, obstacles: ObstacleLayout) -> Self {
+        self.config.obstacles = obstacles;
+        self
+    }
+
+    pub fn spawn(mut self, position: GridPosition, direction: Direction) -> Self {
+        self.config.spawn = Some(position);
+        self.config.spawn_direction = direction;
+        self
+    }
+
+    pub fn ai(mut self, strategy: AiStrategy) -> Self {
+        self.config.ai_mode = true;
+        self.config.ai_strategy = strategy;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    pub fn tick_log(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.tick_log_file = Some(path.into());
+        self
+    }
+
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    pub fn build(self, context: &mut Context) -> GameResult<GameState> {
+        GameState::new(context, self.config)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{Collision, GridPosition, Player};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeadOn {
+    #[default]
+    Draw,
+    Longest,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CollisionRules {
+    pub self_collision: bool,
+    pub tail_pass: bool,
+    pub opponent_collision: bool,
+    pub head_on: HeadOn,
+}
+
+impl CollisionRules {
+    pub fn check(
+        &self,
+        player: &Player,
+        vacated: Option<GridPosition>,
+        obstacles: &[GridPosition],
+        grows: bool,
+    ) -> Option<Collision> {
+        let head = player.head.position;
+        let bites_tail = !self.tail_pass && vacated == Some(head);
+
+        if self.self_collision && player.grace == 0 && (player.collides_with_itself() || bites_tail)
+        {
+            Some(Collision::Itself)
+        } else if obstacles.contains(&head) {
+            Some(Collision::Obstacle)
+        } else if grows {
+            Some(Collision::Food)
+        } else {
+            None
+        }
+    }
+
+    pub fn check_opponent(&self, player: &Player, opponent: &Player) -> Option<Collision> {
+        if !self.opponent_collision {
+            return None;
+        }
+
+        let head = player.head.position;
+        let other_head = opponent.head.position;
+        // Heads that swapped cells passed through each other, which counts as meeting.
+        let swapped = player.body().front().map(|segment| segment.position) == Some(other_head)
+            && opponent.body().front().map(|segment| segment.position) == Some(head);
+
+        if head == other_head || swapped {
+            return match self.head_on {
+                HeadOn::Draw => Some(Collision::Opponent),
+                HeadOn::Longest => {
+                    (player.length() <= opponent.length()).then_some(Collision::Opponent)
+                }
+            };
+        }
+
+        opponent
+            .occupied()
+            .contains_key(&head)
+            .then_some(Collision::Opponent)
+    }
+}
+
+impl Default for CollisionRules {
+    fn default() -> Self {
+        CollisionRules {
+            self_collision: true,
+            tail_pass: true,
+            opponent_collision: true,
+            head_on: HeadOn::Draw,
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::scoring::flat_scoring;
+use crate::{
+    AiStrategy, CollisionRules, ControlScheme, Difficulty, Direction, GameMode, GridPosition,
+    ObstacleLayout, Player, ScoringRule, Theme, WallMode,
+};
+
+const GRID_SIZE: (i16, i16) = (25, 25);
+const GRID_CELL_SIZE: (i16, i16) = (25, 25);
+
+const HIGH_SCORE_FILE: &str = "highscore.txt";
+
+const BONUS_FOOD_CHANCE: f64 = 0.1;
+const BONUS_FOOD_LIFETIME: Duration = Duration::from_secs(5);
+
+const POWER_UP_CHANCE: f64 = 0.05;
+const WALL_PASS_DURATION: Duration = Duration::from_secs(5);
+const SLOW_MOTION_DURATION: Duration = Duration::from_secs(5);
+
+const FRAMES_PER_SECOND: f32 = 8.0;
+const SPEED_UP_PER_FOOD: Duration = Duration::from_millis(2);
+const MIN_FRAME_INTERVAL: Duration = Duration::from_millis(50);
+
+const UNDO_DEPTH: usize = 64;
+
+const TICK_LOG_LENGTH: usize = 256;
+
+const COUNTDOWN: u8 = 3;
+const COUNTDOWN_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Debug)]
+pub struct GameConfig {
+    pub grid: (i16, i16),
+    pub cell: (i16, i16),
+    pub frames_per_second: f32,
+    pub speed_up_per_food: Duration,
+    pub min_frame_interval: Duration,
+    pub wall_mode: WallMode,
+    pub mode: GameMode,
+    pub high_score_file: Option<PathBuf>,
+    pub show_grid: bool,
+    pub show_debug: bool,
+    pub smooth_movement: bool,
+    pub wrap_duration: f32,
+    pub trail_length: usize,
+    pub edge_warning: bool,
+    pub food_highlight: bool,
+    pub seed: Option<u64>,
+    pub food_count: usize,
+    pub food_sequence: Option<Vec<GridPosition>>,
+    pub cycle_food_sequence: bool,
+    pub bonus_food_chance: f64,
+    pub bonus_food_lifetime: Duration,
+    pub power_up_chance: f64,
+    pub wall_pass_duration: Duration,
+    pub slow_motion_duration: Duration,
+    pub hunger_interval: Option<Duration>,
+    pub shrink_interval: Option<Duration>,
+    pub scoring: ScoringRule,
+    pub obstacles: ObstacleLayout,
+    pub portals: Option<(GridPosition, GridPosition)>,
+    pub theme: Theme,
+    pub spawn: Option<GridPosition>,
+    pub spawn_direction: Direction,
+    pub initial_length: usize,
+    pub min_length: usize,
+    pub growth_per_food: usize,
+    pub droppings: bool,
+    pub reach_length: Option<usize>,
+    pub two_player: bool,
+    pub muted: bool,
+    pub confirm_quit: bool,
+    pub pause_on_focus_loss: bool,
+    pub resume_on_focus: bool,
+    pub control_scheme: ControlScheme,
+    pub key_repeat: bool,
+    pub assist: bool,
+    pub self_collision_grace: u64,
+    pub collision_rules: CollisionRules,
+    pub undo_depth: usize,
+    pub step_debug: bool,
+    pub tick_log_file: Option<PathBuf>,
+    pub tick_log_length: usize,
+    pub ai_mode: bool,
+    pub ai_strategy: AiStrategy,
+    pub title_screen: bool,
+    pub countdown: u8,
+    pub countdown_interval: Duration,
+}
+
+impl GameConfig {
+    pub fn from_difficulty(difficulty: Difficulty) -> Self {
+        let normal = GameConfig::default();
+
+        match difficulty {
+            Difficulty::Easy => GameConfig {
+                frames_per_second: 5.0,
+                wall_mode: WallMode::Wrap,
+                obstacles: ObstacleLayout::Empty,
+                ..normal
+            },
+            Difficulty::Normal => normal,
+            Difficulty::Hard => GameConfig {
+                frames_per_second: 12.0,
+                wall_mode: WallMode::Solid,
+                obstacles: ObstacleLayout::Pillars,
+                bonus_food_lifetime: Duration::from_secs(2),
+                ..normal
+            },
+        }
+    }
+
+    pub fn screen_size(&self) -> (f32, f32) {
+        (
+            self.grid.0 as f32 * self.cell.0 as f32,
+            self.grid.1 as f32 * self.cell.1 as f32,
+        )
+    }
+
+    pub fn frame_interval(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.frames_per_second as f64)
+    }
+
+    pub fn spawns(&self) -> Vec<(GridPosition, Direction)> {
+        let first = self
+            .spawn
+            .unwrap_or_else(|| (self.grid.0 / 4, self.grid.1 / 2).into());
+        let mut spawns = vec![(first, self.spawn_direction)];
+
+        if self.two_player {
+            let mirrored = match self.spawn_direction {
+                Direction::Left => Direction::Right,
+                Direction::Right => Direction::Left,
+                direction => direction,
+            };
+
+            spawns.push(((self.grid.0 - 1 - first.x, first.y).into(), mirrored));
+        }
+
+        spawns
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.frames_per_second.is_finite() || self.frames_per_second <= 0.0 {
+            return Err(format!(
+                "frames per second must be positive, got {}",
+                self.frames_per_second
+            ));
+        }
+
+        if self.grid.0 < 1 || self.grid.1 < 1 {
+            return Err(format!("grid {:?} has no cells", self.grid));
+        }
+
+        let obstacles = self.obstacles.positions(self.grid);
+
+        for (position, direction) in self.spawns() {
+            if position.x < 0
+                || position.y < 0
+                || position.x >= self.grid.0
+                || position.y >= self.grid.1
+            {
+                return Err(format!("spawn {:?} is outside the grid", position));
+            }
+
+            let player = Player::spawn(position, direction, self.initial_length, self.grid);
+
+            if player.length() < self.initial_length {
+                return Err(format!(
+                    "a snake of length {} does not fit behind spawn {:?}",
+                    self.initial_length, position
+                ));
+            }
+
+            if obstacles.iter().any(|obstacle| player.occupies(*obstacle)) {
+                return Err(format!("spawn {:?} overlaps an obstacle", position));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn sped_up(&self, frame_interval: Duration) -> Duration {
+        frame_interval
+            .saturating_sub(self.speed_up_per_food)
+            .max(self.min_frame_interval)
+    }
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            grid: GRID_SIZE,
+            cell: GRID_CELL_SIZE,
+            frames_per_second: FRAMES_PER_SECOND,
+            speed_up_per_food: SPEED_UP_PER_FOOD,
+            min_frame_interval: MIN_FRAME_INTERVAL,
+            wall_mode: WallMode::Wrap,
+            mode: GameMode::Classic,
+            high_score_file: Some(PathBuf::from(HIGH_SCORE_FILE)),
+            show_grid: false,
+            show_debug: false,
+            smooth_movement: false,
+            wrap_duration: 1.0,
+            trail_length: 0,
+            edge_warning: false,
+            food_highlight: false,
+            seed: None,
+            food_count: 1,
+            food_sequence: None,
+            cycle_food_sequence: true,
+            bonus_food_chance: BONUS_FOOD_CHANCE,
+            bonus_food_lifetime: BONUS_FOOD_LIFETIME,
+            power_up_chance: POWER_UP_CHANCE,
+            wall_pass_duration: WALL_PASS_DURATION,
+            slow_motion_duration: SLOW_MOTION_DURATION,
+            hunger_interval: None,
+            shrink_interval: None,
+            scoring: flat_scoring,
+            obstacles: ObstacleLayout::Empty,
+            portals: None,
+            theme: Theme::default(),
+            spawn: None,
+            spawn_direction: Direction::Right,
+            initial_length: 2,
+            min_length: 2,
+            growth_per_food: 1,
+            droppings: false,
+            reach_length: None,
+            two_player: false,
+            muted: false,
+            confirm_quit: true,
+            pause_on_focus_loss: true,
+            resume_on_focus: false,
+            control_scheme: ControlScheme::Absolute,
+            key_repeat: false,
+            assist: false,
+            self_collision_grace: 0,
+            collision_rules: CollisionRules::default(),
+            undo_depth: if cfg!(debug_assertions) {
+                UNDO_DEPTH
+            } else {
+                0
+            },
+            step_debug: false,
+            tick_log_file: None,
+            tick_log_length: TICK_LOG_LENGTH,
+            ai_mode: false,
+            ai_strategy: AiStrategy::Greedy,
+            title_screen: true,
+            countdown: COUNTDOWN,
+            countdown_interval: COUNTDOWN_INTERVAL,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use ggez::{graphics, Context, GameResult};
+
+use rand::Rng;
+
+use crate::meshes::CellMeshes;
+use crate::{GridPosition, Player, PowerUp};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FoodKind {
+    Normal,
+    Bonus,
+    PowerUp(PowerUp),
+}
+
+impl FoodKind {
+    pub fn points(&self) -> u32 {
+        match *self {
+            FoodKind::Normal => 1,
+            FoodKind::Bonus => 5,
+            FoodKind::PowerUp(_) => 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Food {
+    pub position: GridPosition,
+    pub kind: FoodKind,
+    pub expires_at: Option<u64>,
+}
+
+impl Food {
+    pub fn new(position: GridPosition) -> Self {
+        Food {
+            position,
+            kind: FoodKind::Normal,
+            expires_at: None,
+        }
+    }
+
+    pub fn bonus(position: GridPosition, expires_at: u64) -> Self {
+        Food {
+            position,
+            kind: FoodKind::Bonus,
+            expires_at: Some(expires_at),
+        }
+    }
+
+    pub fn power_up(position: GridPosition, power_up: PowerUp, expires_at: u64) -> Self {
+        Food {
+            position,
+            kind: FoodKind::PowerUp(power_up),
+            expires_at: Some(expires_at),
+        }
+    }
+
+    pub fn is_expired(&self, tick: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| tick >= expires_at)
+    }
+
+    pub fn respawn_avoiding<R: Rng>(&mut self, rng: &mut R, player: &Player, grid: (i16, i16)) {
+        if let Some(position) =
+            GridPosition::random_free(rng, grid, |position| player.occupies(position))
+        {
+            self.position = position;
+        }
+    }
+
+    pub(crate) fn draw(
+        &self,
+        context: &mut Context,
+        meshes: &CellMeshes,
+        cell: (i16, i16),
+    ) -> GameResult {
+        let param = graphics::DrawParam::default().dest(self.position.to_rect(cell).point());
+
+        let mesh = match self.kind {
+            FoodKind::Normal => &meshes.food,
+            FoodKind::Bonus => &meshes.bonus_food,
+            FoodKind::PowerUp(PowerUp::WallPass) => &meshes.wall_pass,
+            FoodKind::PowerUp(PowerUp::SlowMotion) => &meshes.slow_motion,
+        };
+
+        graphics::draw(context, mesh, param)?;
+
+        if let Some(dot) = &meshes.food_dot {
+            graphics::draw(context, dot, param)?;
+        }
+
+        Ok(())
+    }
+}
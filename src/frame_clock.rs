@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+const MAX_CATCH_UP_STEPS: u32 = 5;
+
+/// Fixed-step accumulator that decouples logic ticks from render frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FrameClock {
+    accumulated: Duration,
+}
+
+impl FrameClock {
+    pub fn new() -> Self {
+        FrameClock::default()
+    }
+
+    pub fn accumulated(&self) -> Duration {
+        self.accumulated
+    }
+
+    pub fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+    }
+
+    /// Adds `elapsed` and calls `step` once for every whole `interval` that
+    /// has built up, returning how many steps ran. `step` returns the interval
+    /// for the next step, or `None` to stop early. After `MAX_CATCH_UP_STEPS`
+    /// the remaining backlog is dropped rather than replayed.
+    pub fn advance(
+        &mut self,
+        elapsed: Duration,
+        mut interval: Duration,
+        mut step: impl FnMut() -> Option<Duration>,
+    ) -> u32 {
+        self.accumulated += elapsed;
+
+        let mut steps = 0;
+        while self.accumulated >= interval {
+            if steps == MAX_CATCH_UP_STEPS {
+                self.accumulated = Duration::ZERO;
+                break;
+            }
+
+            self.accumulated -= interval;
+            steps += 1;
+
+            match step() {
+                Some(next) => interval = next,
+                None => break,
+            }
+        }
+
+        steps
+    }
+}
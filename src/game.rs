@@ -0,0 +1,913 @@
+use std::collections::{HashSet, VecDeque};
+use std::io;
+use std::time::Duration;
+
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+use crate::high_score;
+use crate::{
+    Collision, Direction, Food, FoodKind, GameConfig, GameEvent, GameMode, GameSnapshot,
+    GridPosition, Player, PowerUp, Replay, StepOutcome, TickLog, TickRecord, WallMode,
+};
+
+const SLOW_MOTION_FACTOR: u32 = 2;
+
+struct Checkpoint {
+    snapshot: GameSnapshot,
+    recorded: usize,
+}
+
+pub struct Game {
+    config: GameConfig,
+    players: Vec<Player>,
+    foods: Vec<Food>,
+    food_cursor: usize,
+    obstacles: Vec<GridPosition>,
+    scores: Vec<u32>,
+    hunger: Vec<Duration>,
+    last_meal: Vec<Duration>,
+    high_score: u32,
+    game_over: bool,
+    won: bool,
+    winner: Option<usize>,
+    frame_interval: Duration,
+    slow_motion: Duration,
+    tick: u64,
+    elapsed: Duration,
+    shrinks: i16,
+    rng: ChaCha12Rng,
+    replay: Replay,
+    events: Vec<GameEvent>,
+    history: VecDeque<Checkpoint>,
+    tick_log: TickLog,
+}
+
+impl Game {
+    pub fn new(config: GameConfig) -> Self {
+        let high_score = config
+            .high_score_file
+            .as_deref()
+            .map_or(0, high_score::load);
+
+        let tick_log_length = match config.tick_log_file {
+            Some(_) => config.tick_log_length,
+            None => 0,
+        };
+
+        let replay = Replay::new(0, &config);
+
+        let mut game = Game {
+            frame_interval: config.frame_interval(),
+            slow_motion: Duration::ZERO,
+            config,
+            players: Vec::new(),
+            foods: Vec::new(),
+            food_cursor: 0,
+            obstacles: Vec::new(),
+            scores: Vec::new(),
+            hunger: Vec::new(),
+            last_meal: Vec::new(),
+            high_score,
+            game_over: false,
+            won: false,
+            winner: None,
+            tick: 0,
+            elapsed: Duration::ZERO,
+            shrinks: 0,
+            rng: ChaCha12Rng::seed_from_u64(0),
+            replay,
+            events: Vec::new(),
+            history: VecDeque::new(),
+            tick_log: TickLog::new(tick_log_length),
+        };
+        game.reset();
+
+        game
+    }
+
+    pub fn reset(&mut self) {
+        let grid = self.config.grid;
+
+        self.players = self
+            .config
+            .spawns()
+            .into_iter()
+            .map(|(position, direction)| {
+                Player::spawn(position, direction, self.config.initial_length, grid)
+            })
+            .collect();
+
+        for player in self.players.iter_mut() {
+            player.grace = self.config.self_collision_grace;
+        }
+
+        self.obstacles = self.config.obstacles.positions(grid);
+        if let Some((entry, exit)) = self.config.portals {
+            self.obstacles
+                .retain(|obstacle| *obstacle != entry && *obstacle != exit);
+        }
+
+        let seed = self.config.seed.unwrap_or_else(rand::random);
+
+        self.scores = vec![0; self.players.len()];
+        self.hunger = vec![Duration::ZERO; self.players.len()];
+        self.last_meal = vec![Duration::ZERO; self.players.len()];
+        self.game_over = false;
+        self.won = false;
+        self.winner = None;
+        self.frame_interval = self.config.frame_interval();
+        self.slow_motion = Duration::ZERO;
+        self.tick = 0;
+        self.elapsed = Duration::ZERO;
+        self.shrinks = 0;
+        self.rng = ChaCha12Rng::seed_from_u64(seed);
+        self.replay = Replay::new(seed, &self.config);
+        self.events.clear();
+        self.history.clear();
+        self.tick_log.clear();
+
+        self.foods.clear();
+        self.food_cursor = 0;
+        for _ in 0..self.config.food_count {
+            if let Some(position) = self.next_food_position() {
+                self.foods.push(Food::new(position));
+            }
+        }
+
+        if self.config.food_sequence.is_none() {
+            self.ensure_reachable_food();
+        }
+    }
+
+    pub fn try_new(config: GameConfig) -> Result<Self, String> {
+        config.validate()?;
+
+        Ok(Game::new(config))
+    }
+
+    pub fn from_snapshot(config: GameConfig, snapshot: GameSnapshot) -> Self {
+        let mut game = Game::new(config);
+        game.restore(snapshot);
+
+        game
+    }
+
+    fn restore(&mut self, snapshot: GameSnapshot) {
+        self.players = snapshot.players;
+        for player in &mut self.players {
+            player.reindex();
+        }
+        self.foods = snapshot.foods;
+        self.obstacles = snapshot.obstacles;
+        self.scores = snapshot.scores;
+        self.game_over = snapshot.game_over;
+        self.won = snapshot.won;
+        self.winner = snapshot.winner;
+        self.frame_interval = snapshot.frame_interval;
+        self.tick = snapshot.tick;
+        self.elapsed = snapshot.elapsed;
+        self.hunger = snapshot.hunger;
+        self.hunger.resize(self.players.len(), Duration::ZERO);
+        self.last_meal = snapshot.last_meal;
+        self.last_meal.resize(self.players.len(), Duration::ZERO);
+        self.slow_motion = snapshot.slow_motion;
+        self.shrinks = snapshot.shrinks;
+        self.food_cursor = snapshot.food_cursor;
+        self.rng = snapshot.rng;
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let checkpoint = match self.history.pop_back() {
+            Some(checkpoint) => checkpoint,
+            None => return false,
+        };
+
+        self.restore(checkpoint.snapshot);
+        self.replay.directions.truncate(checkpoint.recorded);
+        self.events.clear();
+
+        true
+    }
+
+    fn checkpoint(&mut self) {
+        if self.config.undo_depth == 0 {
+            return;
+        }
+
+        if self.history.len() >= self.config.undo_depth {
+            self.history.pop_front();
+        }
+
+        self.history.push_back(Checkpoint {
+            snapshot: self.snapshot(),
+            recorded: self.replay.directions.len(),
+        });
+    }
+
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            players: self.players.clone(),
+            foods: self.foods.clone(),
+            obstacles: self.obstacles.clone(),
+            scores: self.scores.clone(),
+            game_over: self.game_over,
+            won: self.won,
+            winner: self.winner,
+            frame_interval: self.frame_interval,
+            tick: self.tick,
+            elapsed: self.elapsed,
+            hunger: self.hunger.clone(),
+            last_meal: self.last_meal.clone(),
+            slow_motion: self.slow_motion,
+            shrinks: self.shrinks,
+            food_cursor: self.food_cursor,
+            rng: self.rng.clone(),
+        }
+    }
+
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    pub fn player(&self) -> &Player {
+        &self.players[0]
+    }
+
+    pub fn player_mut(&mut self) -> &mut Player {
+        &mut self.players[0]
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    pub fn foods(&self) -> &[Food] {
+        &self.foods
+    }
+
+    pub fn obstacles(&self) -> &[GridPosition] {
+        &self.obstacles
+    }
+
+    pub fn portals(&self) -> Option<(GridPosition, GridPosition)> {
+        self.config.portals
+    }
+
+    pub fn score(&self) -> u32 {
+        self.scores[0]
+    }
+
+    pub fn scores(&self) -> &[u32] {
+        &self.scores
+    }
+
+    pub fn high_score(&self) -> u32 {
+        self.high_score
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.game_over
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.won
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.game_over || self.won
+    }
+
+    pub fn winner(&self) -> Option<usize> {
+        self.winner
+    }
+
+    pub fn frame_interval(&self) -> Duration {
+        if !self.slow_motion.is_zero() {
+            self.frame_interval * SLOW_MOTION_FACTOR
+        } else {
+            self.frame_interval
+        }
+    }
+
+    pub fn slow_motion(&self) -> Duration {
+        self.slow_motion
+    }
+
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn shrinks(&self) -> i16 {
+        self.shrinks
+    }
+
+    pub fn time_left(&self) -> Option<Duration> {
+        match self.config.mode {
+            GameMode::Classic | GameMode::Practice => None,
+            GameMode::TimeAttack { limit } => Some(limit.saturating_sub(self.elapsed)),
+        }
+    }
+
+    pub fn replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    pub fn tick_log(&self) -> &TickLog {
+        &self.tick_log
+    }
+
+    pub fn save_tick_log(&self) -> io::Result<()> {
+        match &self.config.tick_log_file {
+            Some(path) => self.tick_log.save(path),
+            None => Ok(()),
+        }
+    }
+
+    pub fn to_ascii(&self) -> String {
+        let grid = self.config.grid;
+        let mut ascii = String::with_capacity((grid.0 as usize + 1) * grid.1 as usize);
+
+        for y in 0..grid.1 {
+            for x in 0..grid.0 {
+                let position = GridPosition::new(x, y);
+
+                let character = if self
+                    .players
+                    .iter()
+                    .any(|player| player.head.position == position)
+                {
+                    'O'
+                } else if self.obstacles.contains(&position) {
+                    '#'
+                } else if self.players.iter().any(|player| player.occupies(position)) {
+                    'o'
+                } else if self.foods.iter().any(|food| food.position == position) {
+                    '*'
+                } else {
+                    '.'
+                };
+
+                ascii.push(character);
+            }
+            ascii.push('\n');
+        }
+
+        ascii
+    }
+
+    pub fn turn(&mut self, direction: Direction) {
+        self.turn_player(0, direction);
+    }
+
+    pub fn turn_all(&mut self, directions: &[Direction]) {
+        for (index, direction) in directions.iter().enumerate() {
+            self.turn_player(index, *direction);
+        }
+    }
+
+    pub fn turn_player(&mut self, index: usize, direction: Direction) {
+        if let Some(player) = self.players.get_mut(index) {
+            player.turn(direction);
+        }
+    }
+
+    pub fn step(&mut self, input: Option<Direction>) -> StepOutcome {
+        self.events.clear();
+
+        if self.is_finished() {
+            return StepOutcome::Skipped;
+        }
+
+        self.checkpoint();
+
+        if let Some(direction) = input {
+            self.turn(direction);
+        }
+
+        if self.config.ai_mode {
+            self.steer_players();
+        }
+
+        let tick = self.tick;
+        let frame_interval = self.frame_interval();
+        self.foods.retain(|food| !food.is_expired(tick));
+
+        for index in 0..self.players.len() {
+            let wall_mode = if self.players[index].wall_pass > 0 {
+                WallMode::Wrap
+            } else {
+                self.config.wall_mode
+            };
+
+            if self.config.assist && self.queued_turn_is_fatal(index, wall_mode) {
+                // Later turns were queued relative to this one, so they go too.
+                self.players[index].queued_turns.clear();
+            }
+
+            let player = &mut self.players[index];
+            player.remember_head(self.config.trail_length);
+            player.update(&self.foods, &self.obstacles, &self.config, wall_mode);
+            player.wall_pass = player.wall_pass.saturating_sub(1);
+        }
+
+        self.check_opponents();
+
+        for index in 0..self.players.len() {
+            self.digest(index);
+            self.verify_collision(index);
+        }
+
+        self.replay
+            .record(self.players.iter().map(|player| player.direction).collect());
+        self.tick_log.record(TickRecord {
+            tick: self.tick,
+            head: self.players[0].head.position,
+            direction: self.players[0].direction,
+            food: self.foods.first().map(|food| food.position),
+            collision: self.players[0].collision,
+        });
+        self.tick += 1;
+        self.elapsed += frame_interval;
+        self.slow_motion = self.slow_motion.saturating_sub(frame_interval);
+
+        if !self.game_over {
+            self.shrink_arena();
+        }
+
+        if !self.game_over && self.foods.is_empty() && !self.has_free_cell() {
+            self.won = true;
+        }
+
+        let target = self.config.reach_length.filter(|_| !self.game_over);
+        if let Some(target) = target {
+            let reached = self
+                .players
+                .iter()
+                .position(|player| player.length() >= target);
+
+            if let Some(index) = reached {
+                self.won = true;
+
+                if self.players.len() > 1 {
+                    self.winner = Some(index);
+                }
+            }
+        }
+
+        if !self.is_finished() && self.time_left() == Some(Duration::ZERO) {
+            self.time_up();
+        }
+
+        if self.is_finished() {
+            self.record_high_score();
+
+            // Saving is best effort everywhere: I/O failures never end a game.
+            let _ = self.save_tick_log();
+        }
+
+        self.outcome()
+    }
+
+    fn outcome(&self) -> StepOutcome {
+        let died = self.events.iter().find_map(|event| match *event {
+            GameEvent::Died { cause } => Some(cause),
+            _ => None,
+        });
+
+        if let Some(cause) = died {
+            StepOutcome::Died(cause)
+        } else if self.events.contains(&GameEvent::TimeUp) {
+            StepOutcome::TimeUp
+        } else if self.won {
+            StepOutcome::Won
+        } else if self
+            .events
+            .iter()
+            .any(|event| matches!(event, GameEvent::AteFood { .. }))
+        {
+            StepOutcome::Ate
+        } else {
+            StepOutcome::Moved
+        }
+    }
+
+    fn steer_players(&mut self) {
+        for player in self.players.iter_mut() {
+            if !player.queued_turns.is_empty() {
+                continue;
+            }
+
+            if let Some(direction) = self.config.ai_strategy.choose(
+                player,
+                &self.foods,
+                &self.obstacles,
+                self.config.grid,
+                self.config.wall_mode,
+            ) {
+                player.turn(direction);
+            }
+        }
+    }
+
+    fn queued_turn_is_fatal(&self, index: usize, wall_mode: WallMode) -> bool {
+        let player = &self.players[index];
+        let opponents: Vec<&Player> = self
+            .players
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != index)
+            .map(|(_, opponent)| opponent)
+            .collect();
+
+        player.queued_turns.front().is_some_and(|turn| {
+            player.would_die(
+                *turn,
+                &self.foods,
+                &self.obstacles,
+                &self.config,
+                wall_mode,
+                &opponents,
+            )
+        })
+    }
+
+    fn check_opponents(&mut self) {
+        let rules = self.config.collision_rules;
+        let players = &self.players;
+
+        let collisions: Vec<Option<Collision>> = players
+            .iter()
+            .enumerate()
+            .map(|(index, player)| {
+                if player
+                    .collision
+                    .is_some_and(|collision| collision.is_fatal())
+                {
+                    return None;
+                }
+
+                players
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, _)| *other != index)
+                    .find_map(|(_, opponent)| rules.check_opponent(player, opponent))
+            })
+            .collect();
+
+        for (player, collision) in self.players.iter_mut().zip(collisions) {
+            if collision.is_some() {
+                player.collision = collision;
+            }
+        }
+    }
+
+    fn digest(&mut self, index: usize) {
+        let interval = match self.config.hunger_interval {
+            Some(interval) => interval,
+            None => return,
+        };
+
+        let frame_interval = self.frame_interval();
+        let player = &mut self.players[index];
+
+        match player.collision {
+            Some(Collision::Food) => self.hunger[index] = Duration::ZERO,
+            Some(collision) if collision.is_fatal() => {}
+            _ => {
+                self.hunger[index] += frame_interval;
+
+                if self.hunger[index] >= interval {
+                    self.hunger[index] -= interval;
+                    player.starve(self.config.min_length);
+                }
+            }
+        }
+    }
+
+    fn verify_collision(&mut self, index: usize) {
+        if let Some(collision) = self.players[index].collision {
+            match collision {
+                Collision::Food => {
+                    let player = &self.players[index];
+
+                    if let Some(eaten) = self.foods.iter().position(|food| player.eats(food)) {
+                        let food = self.foods.remove(eaten);
+
+                        let now = self.elapsed + self.frame_interval();
+                        let since_last_food = now - self.last_meal[index];
+                        let points = (self.config.scoring)(player.length(), since_last_food);
+                        let growth = self.config.growth_per_food.max(1) as u32;
+
+                        self.scores[index] += points * food.kind.points() * growth;
+                        self.last_meal[index] = now;
+                        self.events.push(GameEvent::AteFood {
+                            score: self.scores[index],
+                        });
+                        self.frame_interval = self.config.sped_up(self.frame_interval);
+
+                        if self.config.droppings {
+                            self.obstacles.push(food.position);
+                        }
+
+                        match food.kind {
+                            FoodKind::Normal => self.spawn_food(),
+                            FoodKind::Bonus => {}
+                            FoodKind::PowerUp(power_up) => self.apply_power_up(index, power_up),
+                        }
+                    }
+                }
+
+                Collision::Itself
+                | Collision::Wall
+                | Collision::Obstacle
+                | Collision::Opponent
+                | Collision::Starved => {
+                    self.events.push(GameEvent::Died { cause: collision });
+                    self.declare_loser(index);
+                }
+            }
+        }
+    }
+
+    fn time_up(&mut self) {
+        self.events.push(GameEvent::TimeUp);
+
+        if self.players.len() > 1 {
+            let best = self.scores.iter().copied().max().unwrap_or(0);
+            let leaders: Vec<usize> = (0..self.scores.len())
+                .filter(|index| self.scores[*index] == best)
+                .collect();
+
+            self.winner = match leaders[..] {
+                [leader] => Some(leader),
+                _ => None,
+            };
+        }
+
+        self.game_over = true;
+    }
+
+    fn shrink_arena(&mut self) {
+        let interval = match self.config.shrink_interval {
+            Some(interval) => interval,
+            None => return,
+        };
+
+        let grid = self.config.grid;
+        let ring = self.shrinks;
+
+        // The innermost ring is never walled so there is always somewhere to go.
+        if (ring + 1) * 2 >= grid.0.min(grid.1) || self.elapsed < interval * (ring as u32 + 1) {
+            return;
+        }
+
+        self.shrinks += 1;
+
+        let walled: Vec<GridPosition> = (0..grid.1)
+            .flat_map(|y| (0..grid.0).map(move |x| GridPosition::new(x, y)))
+            .filter(|position| {
+                let edge = position
+                    .x
+                    .min(position.y)
+                    .min(grid.0 - 1 - position.x)
+                    .min(grid.1 - 1 - position.y);
+
+                edge == ring && !self.obstacles.contains(position)
+            })
+            .collect();
+
+        let (buried, kept): (Vec<Food>, Vec<Food>) = self
+            .foods
+            .drain(..)
+            .partition(|food| walled.contains(&food.position));
+        self.foods = kept;
+
+        self.obstacles.extend(walled.iter().copied());
+
+        for index in 0..self.players.len() {
+            let caught = walled
+                .iter()
+                .any(|position| self.players[index].occupies(*position));
+
+            if caught {
+                self.players[index].collision = Some(Collision::Obstacle);
+                self.verify_collision(index);
+            }
+        }
+
+        // Buried food moves inside, keeping its kind and expiry.
+        for mut food in buried {
+            if let Some(position) = self.free_position() {
+                food.position = position;
+                self.foods.push(food);
+            }
+        }
+    }
+
+    fn declare_loser(&mut self, index: usize) {
+        if self.players.len() > 1 {
+            self.winner = if self.game_over {
+                None
+            } else {
+                Some(1 - index)
+            };
+        }
+
+        self.game_over = true;
+    }
+
+    fn spawn_food(&mut self) {
+        if let Some(position) = self.next_food_position() {
+            self.foods.push(Food::new(position));
+        }
+
+        let has_bonus = self.foods.iter().any(|food| food.kind == FoodKind::Bonus);
+
+        if !has_bonus && self.rng.gen_bool(self.config.bonus_food_chance) {
+            if let Some(position) = self.free_position() {
+                let lifetime = self.ticks_for(self.config.bonus_food_lifetime);
+                self.foods.push(Food::bonus(position, self.tick + lifetime));
+            }
+        }
+
+        let has_power_up = self
+            .foods
+            .iter()
+            .any(|food| matches!(food.kind, FoodKind::PowerUp(_)));
+
+        let power_ups: &[PowerUp] = match self.config.wall_mode {
+            WallMode::Wrap => &[PowerUp::SlowMotion],
+            WallMode::Solid | WallMode::WrapHorizontal | WallMode::WrapVertical => {
+                &[PowerUp::WallPass, PowerUp::SlowMotion]
+            }
+        };
+
+        if !has_power_up && self.rng.gen_bool(self.config.power_up_chance) {
+            if let (Some(position), Some(power_up)) = (
+                self.free_position(),
+                power_ups.choose(&mut self.rng).copied(),
+            ) {
+                let lifetime = self.ticks_for(self.config.bonus_food_lifetime);
+                self.foods
+                    .push(Food::power_up(position, power_up, self.tick + lifetime));
+            }
+        }
+    }
+
+    fn apply_power_up(&mut self, index: usize, power_up: PowerUp) {
+        match power_up {
+            PowerUp::WallPass => {
+                self.players[index].wall_pass += self.ticks_for(self.config.wall_pass_duration);
+            }
+            PowerUp::SlowMotion => {
+                self.slow_motion += self.config.slow_motion_duration;
+            }
+        }
+    }
+
+    fn ticks_for(&self, duration: Duration) -> u64 {
+        let ticks = (duration.as_secs_f64() / self.frame_interval().as_secs_f64()).round() as u64;
+
+        ticks.max(1)
+    }
+
+    fn next_food_position(&mut self) -> Option<GridPosition> {
+        let sequence = match &self.config.food_sequence {
+            Some(sequence) if !sequence.is_empty() => sequence,
+            Some(_) => return None,
+            None => return self.free_position(),
+        };
+
+        let remaining = if self.config.cycle_food_sequence {
+            sequence.len()
+        } else {
+            sequence.len().saturating_sub(self.food_cursor)
+        };
+
+        // Scripted cells that are taken are skipped rather than stacked on.
+        for _ in 0..remaining {
+            let position = sequence[self.food_cursor % sequence.len()];
+            self.food_cursor += 1;
+
+            if !is_blocked(&self.players, &self.foods, &self.obstacles, position)
+                && !is_portal(self.config.portals, position)
+            {
+                return Some(position);
+            }
+        }
+
+        None
+    }
+
+    fn free_position(&mut self) -> Option<GridPosition> {
+        let players = &self.players;
+        let foods = &self.foods;
+        let obstacles = &self.obstacles;
+        let portals = self.config.portals;
+
+        GridPosition::random_free(&mut self.rng, self.config.grid, |position| {
+            is_blocked(players, foods, obstacles, position) || is_portal(portals, position)
+        })
+    }
+
+    fn ensure_reachable_food(&mut self) {
+        let reachable = self.reachable_cells(self.players[0].head.position);
+
+        if self
+            .foods
+            .iter()
+            .any(|food| reachable.contains(&food.position))
+        {
+            return;
+        }
+
+        let players = &self.players;
+        let foods = &self.foods;
+        let obstacles = &self.obstacles;
+        let portals = self.config.portals;
+
+        let position = GridPosition::random_free(&mut self.rng, self.config.grid, |position| {
+            !reachable.contains(&position)
+                || is_blocked(players, foods, obstacles, position)
+                || is_portal(portals, position)
+        });
+
+        if let (Some(position), Some(food)) = (position, self.foods.first_mut()) {
+            food.position = position;
+        }
+    }
+
+    fn reachable_cells(&self, start: GridPosition) -> HashSet<GridPosition> {
+        let mut seen = HashSet::from([start]);
+        let mut frontier = VecDeque::from([start]);
+
+        while let Some(position) = frontier.pop_front() {
+            for direction in Direction::ALL {
+                let next = match position.moved(direction, self.config.grid, self.config.wall_mode)
+                {
+                    Some(next) => next,
+                    None => continue,
+                };
+
+                let blocked = self.obstacles.contains(&next)
+                    || self.players.iter().any(|player| player.occupies(next));
+
+                if !blocked && seen.insert(next) {
+                    frontier.push_back(next);
+                }
+            }
+        }
+
+        seen
+    }
+
+    fn has_free_cell(&self) -> bool {
+        let grid = self.config.grid;
+
+        (0..grid.1)
+            .flat_map(|y| (0..grid.0).map(move |x| GridPosition::new(x, y)))
+            .any(|position| {
+                !is_blocked(&self.players, &self.foods, &self.obstacles, position)
+                    && !is_portal(self.config.portals, position)
+            })
+    }
+
+    pub fn record_high_score(&mut self) {
+        let best = self.scores.iter().copied().max().unwrap_or(0);
+
+        if best <= self.high_score {
+            return;
+        }
+
+        self.high_score = best;
+
+        if let Some(path) = &self.config.high_score_file {
+            let _ = high_score::save(path, self.high_score);
+        }
+    }
+}
+
+fn is_blocked(
+    players: &[Player],
+    foods: &[Food],
+    obstacles: &[GridPosition],
+    position: GridPosition,
+) -> bool {
+    players.iter().any(|player| player.occupies(position))
+        || foods.iter().any(|food| food.position == position)
+        || obstacles.contains(&position)
+}
+
+fn is_portal(portals: Option<(GridPosition, GridPosition)>, position: GridPosition) -> bool {
+    portals.is_some_and(|(entry, exit)| position == entry || position == exit)
+}
@@ -0,0 +1,9 @@
+use crate::Collision;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameEvent {
+    AteFood { score: u32 },
+    Died { cause: Collision },
+    TimeUp,
+    Restarted,
+}
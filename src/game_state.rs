@@ -0,0 +1,908 @@
+use ggez::event::EventHandler;
+use ggez::event::KeyCode;
+use ggez::{event, graphics, Context, GameError, GameResult};
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::keyboard;
+use crate::meshes::CellMeshes;
+use crate::sounds::Sounds;
+use crate::{
+    AiStrategy, Collision, Direction, FrameClock, Game, GameConfig, GameEvent, GameMode,
+    GameStateBuilder, GridPosition, KeyMap, Replay, StepOutcome,
+};
+
+enum Phase {
+    Title,
+    Countdown { count: u8, since: Instant },
+    Playing,
+    Paused,
+    GameOver,
+    Won,
+}
+
+impl Phase {
+    fn countdown(config: &GameConfig) -> Self {
+        Phase::Countdown {
+            count: config.countdown,
+            since: Instant::now(),
+        }
+    }
+}
+
+fn demo_config(config: &GameConfig) -> GameConfig {
+    GameConfig {
+        ai_mode: true,
+        ai_strategy: AiStrategy::Bfs,
+        high_score_file: None,
+        hunger_interval: None,
+        mode: GameMode::Classic,
+        muted: true,
+        ..config.clone()
+    }
+}
+
+pub struct GameState {
+    config: GameConfig,
+    game: Game,
+    phase: Phase,
+    show_debug: bool,
+    dump_error: Option<String>,
+    show_coordinates: bool,
+    show_occupied: bool,
+    stepping: bool,
+    confirming_quit: bool,
+    paused_by_focus: bool,
+    cause: Option<Collision>,
+    last_update: Instant,
+    clock: FrameClock,
+    meshes: Option<CellMeshes>,
+    playback: Option<VecDeque<Vec<Direction>>>,
+    key_maps: Vec<KeyMap>,
+    on_event: Option<Box<dyn FnMut(GameEvent)>>,
+    sounds: Sounds,
+}
+
+impl GameState {
+    pub fn new(context: &mut Context, config: GameConfig) -> GameResult<Self> {
+        let title_screen = config.title_screen;
+
+        GameState::build(context, config, title_screen)
+    }
+
+    pub fn builder() -> GameStateBuilder {
+        GameStateBuilder::new()
+    }
+
+    fn build(context: &mut Context, config: GameConfig, title_screen: bool) -> GameResult<Self> {
+        config.validate().map_err(GameError::ConfigError)?;
+
+        let (phase, game) = if title_screen {
+            (Phase::Title, Game::new(demo_config(&config)))
+        } else {
+            (Phase::countdown(&config), Game::new(config.clone()))
+        };
+
+        let key_maps = if config.two_player {
+            vec![KeyMap::wasd(), KeyMap::arrows()]
+        } else {
+            vec![KeyMap::default()]
+        };
+
+        Ok(GameState {
+            phase,
+            show_debug: config.show_debug,
+            dump_error: None,
+            show_coordinates: false,
+            show_occupied: false,
+            stepping: config.step_debug,
+            confirming_quit: false,
+            paused_by_focus: false,
+            cause: None,
+            game,
+            config,
+            last_update: Instant::now(),
+            clock: FrameClock::new(),
+            meshes: None,
+            playback: None,
+            key_maps,
+            on_event: None,
+            sounds: Sounds::load(context),
+        })
+    }
+
+    pub fn from_replay(
+        context: &mut Context,
+        config: GameConfig,
+        replay: &Replay,
+    ) -> GameResult<Self> {
+        let mut state = GameState::build(context, replay.configure(config), false)?;
+        state.playback = Some(replay.directions.iter().cloned().collect());
+
+        Ok(state)
+    }
+
+    pub fn is_playing_back(&self) -> bool {
+        self.playback.is_some()
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn game_mut(&mut self) -> &mut Game {
+        &mut self.game
+    }
+
+    pub fn to_ascii(&self) -> String {
+        self.game.to_ascii()
+    }
+
+    pub fn undo(&mut self) -> bool {
+        if !self.game.undo() {
+            return false;
+        }
+
+        if matches!(self.phase, Phase::GameOver | Phase::Won) {
+            self.phase = Phase::Paused;
+        }
+        self.cause = None;
+
+        true
+    }
+
+    pub fn key_map(&self, player: usize) -> Option<&KeyMap> {
+        self.key_maps.get(player)
+    }
+
+    pub fn set_key_map(&mut self, player: usize, key_map: KeyMap) {
+        if let Some(existing) = self.key_maps.get_mut(player) {
+            *existing = key_map;
+        }
+    }
+
+    pub fn set_event_handler<F>(&mut self, handler: F)
+    where
+        F: FnMut(GameEvent) + 'static,
+    {
+        self.on_event = Some(Box::new(handler));
+    }
+
+    fn emit(&mut self, event: GameEvent) {
+        if let Some(handler) = self.on_event.as_mut() {
+            handler(event);
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        matches!(self.phase, Phase::Paused)
+    }
+
+    pub fn is_title(&self) -> bool {
+        matches!(self.phase, Phase::Title)
+    }
+
+    pub fn is_counting_down(&self) -> bool {
+        matches!(self.phase, Phase::Countdown { .. })
+    }
+
+    fn start_playing(&mut self) {
+        self.phase = Phase::Playing;
+        self.last_update = Instant::now();
+        self.clock.reset();
+    }
+
+    fn count_down(&mut self) {
+        if let Phase::Countdown { count, since } = self.phase {
+            if since.elapsed() < self.game.config().countdown_interval {
+                return;
+            }
+
+            match count {
+                0 => self.start_playing(),
+                count => {
+                    self.phase = Phase::Countdown {
+                        count: count - 1,
+                        since: Instant::now(),
+                    }
+                }
+            }
+        }
+    }
+
+    fn toggle_pause(&mut self) {
+        self.paused_by_focus = false;
+
+        match self.phase {
+            Phase::Playing => self.phase = Phase::Paused,
+            Phase::Paused => self.start_playing(),
+            _ => {}
+        }
+    }
+
+    fn steer(&mut self, keycode: KeyCode, repeat: bool) {
+        if self.playback.is_none() {
+            keyboard::steer(&mut self.game, &self.key_maps, keycode, repeat);
+        }
+    }
+
+    fn quit(&mut self, context: &mut Context) {
+        self.game.record_high_score();
+        event::quit(context);
+    }
+
+    fn cancel_quit(&mut self) {
+        self.confirming_quit = false;
+        self.last_update = Instant::now();
+        self.clock.reset();
+    }
+
+    pub fn reset(&mut self) {
+        if self.is_title() {
+            self.game = Game::new(self.config.clone());
+        } else {
+            self.game.reset();
+        }
+
+        self.phase = Phase::countdown(&self.config);
+        self.stepping = self.config.step_debug;
+        self.cause = None;
+        self.last_update = Instant::now();
+        self.clock.reset();
+        self.emit(GameEvent::Restarted);
+    }
+
+    fn step(&mut self, context: &mut Context) -> StepOutcome {
+        if let Some(directions) = self.playback.as_mut().and_then(VecDeque::pop_front) {
+            self.game.turn_all(&directions);
+        }
+        if self.playback.as_ref().is_some_and(VecDeque::is_empty) {
+            self.playback = None;
+        }
+
+        let outcome = self.game.step(None);
+
+        if let StepOutcome::Died(cause) = outcome {
+            self.cause = Some(cause);
+        }
+
+        if self.is_title() {
+            return outcome;
+        }
+
+        match outcome {
+            StepOutcome::Won => self.phase = Phase::Won,
+            StepOutcome::Died(_) | StepOutcome::TimeUp => self.phase = Phase::GameOver,
+            StepOutcome::Moved | StepOutcome::Ate | StepOutcome::Skipped => {}
+        }
+
+        if let Some(handler) = self.on_event.as_mut() {
+            for event in self.game.events() {
+                handler(*event);
+            }
+        }
+
+        if !self.game.config().muted {
+            for event in self.game.events() {
+                self.sounds.play(context, event);
+            }
+        }
+
+        outcome
+    }
+
+    fn progress(&self) -> f32 {
+        if !self.game.config().smooth_movement
+            || !matches!(self.phase, Phase::Title | Phase::Playing)
+            || self.game.is_finished()
+        {
+            return 0.0;
+        }
+
+        let progress =
+            self.clock.accumulated().as_secs_f32() / self.game.frame_interval().as_secs_f32();
+
+        progress.min(1.0)
+    }
+
+    fn draw_score(&self, context: &mut Context) -> GameResult {
+        let players = self.game.players();
+        let scores = match self.game.scores() {
+            [score] => format!("Score: {}  Length: {}", score, players[0].length()),
+            scores => scores
+                .iter()
+                .zip(players)
+                .enumerate()
+                .map(|(index, (score, player))| {
+                    format!("P{}: {} ({})", index + 1, score, player.length())
+                })
+                .collect::<Vec<_>>()
+                .join("  "),
+        };
+
+        let time_left = self
+            .game
+            .time_left()
+            .map(|time_left| format!("  Time: {}", time_left.as_secs_f32().ceil()))
+            .unwrap_or_default();
+
+        let wall_pass = match self.game.player().wall_pass {
+            0 => String::new(),
+            ticks => format!(
+                "  Pass: {}",
+                (self.game.frame_interval().as_secs_f32() * ticks as f32).ceil()
+            ),
+        };
+
+        let slow_motion = match self.game.slow_motion() {
+            left if left.is_zero() => String::new(),
+            left => format!("  Slow: {}", left.as_secs_f32().ceil()),
+        };
+
+        let text = graphics::Text::new(graphics::TextFragment {
+            text: format!(
+                "{}  Best: {}{}{}{}",
+                scores,
+                self.game.high_score(),
+                time_left,
+                wall_pass,
+                slow_motion
+            ),
+            font: Some(graphics::Font::default()),
+            color: Some(self.game.config().theme.text),
+            scale: Some(graphics::PxScale::from(24.0)),
+        });
+
+        graphics::draw(
+            context,
+            &text,
+            graphics::DrawParam::default().dest([4.0, 4.0]),
+        )?;
+
+        Ok(())
+    }
+
+    fn draw_edge_warnings(&self, context: &mut Context) -> GameResult {
+        let (width, height) = self.game.config().screen_size();
+        let cell = self.game.config().cell;
+        let (thickness_x, thickness_y) = (cell.0 as f32 / 4.0, cell.1 as f32 / 4.0);
+
+        for direction in self
+            .game
+            .players()
+            .iter()
+            .filter_map(|player| player.edge_warning)
+        {
+            let edge = match direction {
+                Direction::Up => graphics::Rect::new(0.0, 0.0, width, thickness_y),
+                Direction::Down => {
+                    graphics::Rect::new(0.0, height - thickness_y, width, thickness_y)
+                }
+                Direction::Left => graphics::Rect::new(0.0, 0.0, thickness_x, height),
+                Direction::Right => {
+                    graphics::Rect::new(width - thickness_x, 0.0, thickness_x, height)
+                }
+            };
+
+            let mesh = graphics::Mesh::new_rectangle(
+                context,
+                graphics::DrawMode::fill(),
+                edge,
+                self.game.config().theme.warning,
+            )?;
+            graphics::draw(context, &mesh, graphics::DrawParam::default())?;
+        }
+
+        Ok(())
+    }
+
+    fn draw_debug(&self, context: &mut Context) -> GameResult {
+        let mut status = format!(
+            "FPS: {:.0}  Tick: {:.1}ms",
+            ggez::timer::fps(context),
+            self.game.frame_interval().as_secs_f64() * 1000.0
+        );
+        if let Some(error) = &self.dump_error {
+            status.push_str(&format!("  Dump failed: {}", error));
+        }
+
+        let text = graphics::Text::new(graphics::TextFragment {
+            text: status,
+            font: Some(graphics::Font::default()),
+            color: Some(self.game.config().theme.text),
+            scale: Some(graphics::PxScale::from(16.0)),
+        });
+
+        let (_, height) = self.game.config().screen_size();
+
+        graphics::draw(
+            context,
+            &text,
+            graphics::DrawParam::default().dest([4.0, height - 20.0]),
+        )?;
+
+        Ok(())
+    }
+
+    fn draw_occupied(&self, context: &mut Context) -> GameResult {
+        let config = self.game.config();
+        let color = graphics::Color {
+            a: 0.4,
+            ..config.theme.warning
+        };
+        let mut builder = graphics::MeshBuilder::new();
+        let mut empty = true;
+
+        for position in self
+            .game
+            .players()
+            .iter()
+            .flat_map(|player| player.occupied().keys())
+        {
+            builder.rectangle(
+                graphics::DrawMode::fill(),
+                position.to_rect(config.cell),
+                color,
+            )?;
+            empty = false;
+        }
+
+        if empty {
+            return Ok(());
+        }
+
+        let mesh = builder.build(context)?;
+        graphics::draw(context, &mesh, graphics::DrawParam::default())
+    }
+
+    fn draw_coordinates(&self, context: &mut Context) -> GameResult {
+        let config = self.game.config();
+        let (grid, cell) = (config.grid, config.cell);
+        let color = graphics::Color {
+            a: 0.5,
+            ..config.theme.text
+        };
+
+        for y in 0..grid.1 {
+            for x in 0..grid.0 {
+                let text = graphics::Text::new(graphics::TextFragment {
+                    text: format!("{},{}", x, y),
+                    font: Some(graphics::Font::default()),
+                    color: Some(color),
+                    scale: Some(graphics::PxScale::from(9.0)),
+                });
+                let origin = GridPosition::new(x, y).to_rect(cell);
+
+                graphics::queue_text(context, &text, [origin.x + 1.0, origin.y + 1.0], None);
+            }
+        }
+
+        graphics::draw_queued_text(
+            context,
+            graphics::DrawParam::default(),
+            None,
+            graphics::FilterMode::Linear,
+        )
+    }
+
+    fn draw_quit_prompt(&self, context: &mut Context) -> GameResult {
+        let text = graphics::Text::new(graphics::TextFragment {
+            text: "Quit? Y/N".to_string(),
+            font: Some(graphics::Font::default()),
+            color: Some(self.game.config().theme.text),
+            scale: Some(graphics::PxScale::from(50.0)),
+        });
+
+        graphics::draw(
+            context,
+            &text,
+            graphics::DrawParam::default().dest([4.0, 40.0]),
+        )?;
+
+        Ok(())
+    }
+
+    fn draw_paused(&self, context: &mut Context) -> GameResult {
+        let text = graphics::Text::new(graphics::TextFragment {
+            text: "PAUSED".to_string(),
+            font: Some(graphics::Font::default()),
+            color: Some(self.game.config().theme.text),
+            scale: Some(graphics::PxScale::from(50.0)),
+        });
+
+        graphics::draw(
+            context,
+            &text,
+            graphics::DrawParam::default().dest([4.0, 40.0]),
+        )?;
+
+        Ok(())
+    }
+
+    fn draw_title(&self, context: &mut Context) -> GameResult {
+        let text = graphics::Text::new(graphics::TextFragment {
+            text: "SNAKE\nPress any key to start".to_string(),
+            font: Some(graphics::Font::default()),
+            color: Some(self.game.config().theme.text),
+            scale: Some(graphics::PxScale::from(50.0)),
+        });
+
+        graphics::draw(
+            context,
+            &text,
+            graphics::DrawParam::default().dest([4.0, 40.0]),
+        )?;
+
+        Ok(())
+    }
+
+    fn draw_countdown(&self, context: &mut Context, count: u8) -> GameResult {
+        let text = match count {
+            0 => "Go!".to_string(),
+            count => count.to_string(),
+        };
+
+        let text = graphics::Text::new(graphics::TextFragment {
+            text,
+            font: Some(graphics::Font::default()),
+            color: Some(self.game.config().theme.text),
+            scale: Some(graphics::PxScale::from(80.0)),
+        });
+
+        graphics::draw(
+            context,
+            &text,
+            graphics::DrawParam::default().dest([4.0, 40.0]),
+        )?;
+
+        Ok(())
+    }
+
+    fn draw_won(&self, context: &mut Context) -> GameResult {
+        let text = graphics::Text::new(graphics::TextFragment {
+            text: format!(
+                "You Win!\nScore: {}\nPress Enter to restart",
+                self.game.score()
+            ),
+            font: Some(graphics::Font::default()),
+            color: Some(self.game.config().theme.text),
+            scale: Some(graphics::PxScale::from(50.0)),
+        });
+
+        graphics::draw(context, &text, graphics::DrawParam::default())?;
+
+        Ok(())
+    }
+
+    fn draw_game_over(&self, context: &mut Context) -> GameResult {
+        let result = if self.game.players().len() > 1 {
+            match self.game.winner() {
+                Some(winner) => format!("Player {} wins!", winner + 1),
+                None => "Draw!".to_string(),
+            }
+        } else {
+            format!("Score: {}", self.game.score())
+        };
+
+        let text = graphics::Text::new(graphics::TextFragment {
+            text: format!(
+                "Game Over!\n{}\n{}\nPress Enter to restart",
+                match self.cause {
+                    None if self.game.time_left() == Some(Duration::ZERO) => "Time's up!",
+                    cause => cause_message(cause),
+                },
+                result
+            ),
+            font: Some(graphics::Font::default()),
+            color: Some(graphics::Color::new(1.0, 0.0, 0.0, 1.0)),
+            scale: Some(graphics::PxScale::from(50.0)),
+        });
+
+        graphics::draw(context, &text, graphics::DrawParam::default())?;
+
+        Ok(())
+    }
+}
+
+impl EventHandler<GameError> for GameState {
+    fn update(&mut self, context: &mut Context) -> GameResult {
+        if self.confirming_quit {
+            return Ok(());
+        }
+
+        match self.phase {
+            Phase::Title if self.game.is_finished() => self.game.reset(),
+            Phase::Playing if self.stepping => return Ok(()),
+            Phase::Title | Phase::Playing => {}
+            Phase::Countdown { .. } => {
+                self.count_down();
+                return Ok(());
+            }
+            Phase::Paused | Phase::GameOver | Phase::Won => return Ok(()),
+        }
+
+        let now = Instant::now();
+        let elapsed = now - self.last_update;
+        self.last_update = now;
+
+        let mut clock = self.clock;
+        clock.advance(elapsed, self.game.frame_interval(), || {
+            match self.step(context) {
+                StepOutcome::Moved | StepOutcome::Ate => Some(self.game.frame_interval()),
+                StepOutcome::Died(_)
+                | StepOutcome::TimeUp
+                | StepOutcome::Won
+                | StepOutcome::Skipped => None,
+            }
+        });
+        self.clock = clock;
+
+        Ok(())
+    }
+
+    fn draw(&mut self, context: &mut Context) -> GameResult {
+        graphics::clear(context, self.game.config().theme.background);
+
+        if self.meshes.is_none() {
+            self.meshes = Some(CellMeshes::new(context, self.game.config())?);
+        }
+
+        let progress = self.progress();
+
+        if let Some(meshes) = &mut self.meshes {
+            if let Some(checkerboard) = &meshes.checkerboard {
+                graphics::draw(context, checkerboard, graphics::DrawParam::default())?;
+            }
+            if let Some(tiles) = &meshes.background_tiles {
+                graphics::draw(context, tiles, graphics::DrawParam::default())?;
+            }
+
+            if let Some(grid_lines) = &meshes.grid_lines {
+                graphics::draw(context, grid_lines, graphics::DrawParam::default())?;
+            }
+
+            let cell = self.game.config().cell;
+
+            meshes.obstacles.clear();
+            for obstacle in self.game.obstacles() {
+                meshes
+                    .obstacles
+                    .add(graphics::DrawParam::default().dest(obstacle.to_rect(cell).point()));
+            }
+            meshes
+                .obstacles
+                .draw(context, graphics::DrawParam::default())?;
+
+            if let Some((entry, exit)) = self.game.portals() {
+                for portal in [entry, exit] {
+                    graphics::draw(
+                        context,
+                        &meshes.portal,
+                        graphics::DrawParam::default().dest(portal.to_rect(cell).point()),
+                    )?;
+                }
+            }
+
+            for (player, snake_meshes) in self.game.players().iter().zip(meshes.snakes.iter_mut()) {
+                player.draw(context, snake_meshes, self.game.config(), progress)?;
+            }
+            for food in self.game.foods() {
+                food.draw(context, meshes, cell)?;
+            }
+
+            if let Some(highlight) = &meshes.food_highlight {
+                let grid = self.game.config().grid;
+                let seconds = ggez::timer::time_since_start(context).as_secs_f32();
+                let color = graphics::Color {
+                    a: 0.5 + 0.5 * (seconds * std::f32::consts::TAU).sin(),
+                    ..self.game.config().theme.text
+                };
+
+                for food in self.game.foods().iter().filter(|food| {
+                    self.game.players().iter().any(|player| {
+                        player
+                            .head
+                            .position
+                            .neighbors(grid)
+                            .contains(&food.position)
+                    })
+                }) {
+                    graphics::draw(
+                        context,
+                        highlight,
+                        graphics::DrawParam::default()
+                            .dest(food.position.to_rect(cell).point())
+                            .color(color),
+                    )?;
+                }
+            }
+        }
+        if self.game.config().edge_warning && !self.game.is_finished() {
+            self.draw_edge_warnings(context)?;
+        }
+
+        if !self.is_title() {
+            self.draw_score(context)?;
+        }
+
+        if self.show_debug {
+            if self.show_occupied {
+                self.draw_occupied(context)?;
+            }
+            if self.show_coordinates {
+                self.draw_coordinates(context)?;
+            }
+            self.draw_debug(context)?;
+        }
+
+        if self.confirming_quit {
+            self.draw_quit_prompt(context)?;
+        } else {
+            match self.phase {
+                Phase::Title => self.draw_title(context)?,
+                Phase::Countdown { count, .. } => self.draw_countdown(context, count)?,
+                Phase::Playing => {}
+                Phase::Paused => self.draw_paused(context)?,
+                Phase::GameOver => self.draw_game_over(context)?,
+                Phase::Won => self.draw_won(context)?,
+            }
+        }
+
+        graphics::present(context)?;
+
+        ggez::timer::yield_now();
+
+        Ok(())
+    }
+
+    fn focus_event(&mut self, _context: &mut Context, gained: bool) {
+        if !self.config.pause_on_focus_loss {
+            return;
+        }
+
+        match self.phase {
+            Phase::Playing if !gained => {
+                self.phase = Phase::Paused;
+                self.paused_by_focus = true;
+            }
+            Phase::Paused if gained && self.paused_by_focus && self.config.resume_on_focus => {
+                self.paused_by_focus = false;
+                self.start_playing();
+            }
+            _ => {}
+        }
+    }
+
+    fn resize_event(&mut self, context: &mut Context, width: f32, height: f32) {
+        let (logical_width, logical_height) = self.game.config().screen_size();
+        let scale = (width / logical_width).min(height / logical_height);
+        let (view_width, view_height) = (width / scale, height / scale);
+
+        let view = graphics::Rect::new(
+            (logical_width - view_width) / 2.0,
+            (logical_height - view_height) / 2.0,
+            view_width,
+            view_height,
+        );
+
+        let _ = graphics::set_screen_coordinates(context, view);
+    }
+
+    fn key_down_event(
+        &mut self,
+        context: &mut Context,
+        keycode: KeyCode,
+        _keymods: event::KeyMods,
+        repeat: bool,
+    ) {
+        if repeat {
+            // Held keys may only steer; repeating toggles would flicker.
+            if !self.confirming_quit && matches!(self.phase, Phase::Playing) {
+                self.steer(keycode, true);
+            }
+
+            return;
+        }
+
+        if self.confirming_quit {
+            match keycode {
+                KeyCode::Y | KeyCode::Return => self.quit(context),
+                KeyCode::N | KeyCode::Escape => self.cancel_quit(),
+                _ => {}
+            }
+
+            return;
+        }
+
+        if keycode == KeyCode::Escape {
+            if self.game.config().confirm_quit {
+                self.confirming_quit = true;
+            } else {
+                self.quit(context);
+            }
+
+            return;
+        }
+
+        if keycode == KeyCode::F12 {
+            self.dump_error = self
+                .game
+                .save_tick_log()
+                .err()
+                .map(|error| error.to_string());
+            return;
+        }
+
+        if keycode == KeyCode::F3 {
+            self.show_debug = !self.show_debug;
+            return;
+        }
+
+        if keycode == KeyCode::F4 && self.show_debug {
+            self.show_coordinates = !self.show_coordinates;
+            return;
+        }
+
+        if keycode == KeyCode::F5 && self.show_debug {
+            self.show_occupied = !self.show_occupied;
+            return;
+        }
+
+        match self.phase {
+            Phase::Title => {
+                self.reset();
+                return;
+            }
+            _ if keycode == KeyCode::R => {
+                self.reset();
+                return;
+            }
+            _ if keycode == KeyCode::Back && self.show_debug => {
+                self.undo();
+                return;
+            }
+            Phase::GameOver | Phase::Won => {
+                if keycode == KeyCode::Return {
+                    self.reset();
+                }
+
+                return;
+            }
+            Phase::Countdown { .. } => {
+                self.start_playing();
+                return;
+            }
+            Phase::Playing if self.config.step_debug && keycode == KeyCode::Period => {
+                self.stepping = true;
+                self.clock.reset();
+                self.step(context);
+                return;
+            }
+            Phase::Playing if self.stepping && keycode == KeyCode::Return => {
+                self.stepping = false;
+                self.start_playing();
+                return;
+            }
+            _ if keycode == KeyCode::Space || keycode == KeyCode::P => {
+                self.toggle_pause();
+                return;
+            }
+            Phase::Paused => return,
+            Phase::Playing => {}
+        }
+
+        self.steer(keycode, false);
+    }
+}
+
+fn cause_message(cause: Option<Collision>) -> &'static str {
+    match cause {
+        Some(Collision::Wall) => "Hit a wall",
+        Some(Collision::Itself) => "Ate yourself",
+        Some(Collision::Obstacle) => "Hit an obstacle",
+        Some(Collision::Opponent) => "Ran into the other snake",
+        Some(Collision::Starved) => "Starved",
+        Some(Collision::Food) | None => "You died",
+    }
+}
@@ -0,0 +1,259 @@
+use ggez::graphics;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use std::cmp::Ordering;
+use std::ops::Add;
+
+use crate::Direction;
+
+const RANDOM_FREE_ATTEMPTS: usize = 32;
+
+trait ModulusSigned {
+    fn modulus_signed(&self, n: Self) -> Self;
+}
+
+impl<T> ModulusSigned for T
+where
+    T: std::ops::Add<Output = T> + std::ops::Rem<Output = T> + Clone,
+{
+    fn modulus_signed(&self, n: T) -> T {
+        (self.clone() % n.clone() + n.clone()) % n
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WallMode {
+    Wrap,
+    Solid,
+    WrapHorizontal,
+    WrapVertical,
+}
+
+impl WallMode {
+    pub fn is_solid(&self, direction: Direction) -> bool {
+        let horizontal = matches!(direction, Direction::Left | Direction::Right);
+
+        match *self {
+            WallMode::Wrap => false,
+            WallMode::Solid => true,
+            WallMode::WrapHorizontal => !horizontal,
+            WallMode::WrapVertical => horizontal,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridPosition {
+    pub x: i16,
+    pub y: i16,
+}
+
+impl GridPosition {
+    pub fn new(x: i16, y: i16) -> Self {
+        GridPosition { x, y }
+    }
+
+    pub fn random<R: Rng>(rng: &mut R, max_x: i16, max_y: i16) -> Self {
+        (rng.gen_range(0..max_x), rng.gen_range(0..max_y)).into()
+    }
+
+    pub fn random_free<R, F>(rng: &mut R, grid: (i16, i16), is_blocked: F) -> Option<Self>
+    where
+        R: Rng,
+        F: Fn(GridPosition) -> bool,
+    {
+        for _ in 0..RANDOM_FREE_ATTEMPTS {
+            let candidate = GridPosition::random(rng, grid.0, grid.1);
+
+            if !is_blocked(candidate) {
+                return Some(candidate);
+            }
+        }
+
+        let free_cells: Vec<GridPosition> = (0..grid.1)
+            .flat_map(|y| (0..grid.0).map(move |x| GridPosition::new(x, y)))
+            .filter(|position| !is_blocked(*position))
+            .collect();
+
+        free_cells.choose(rng).copied()
+    }
+
+    pub fn new_from_move(position: GridPosition, direction: Direction, grid: (i16, i16)) -> Self {
+        match direction {
+            Direction::Up => GridPosition::new(position.x, (position.y - 1).modulus_signed(grid.1)),
+            Direction::Down => {
+                GridPosition::new(position.x, (position.y + 1).modulus_signed(grid.1))
+            }
+            Direction::Left => {
+                GridPosition::new((position.x - 1).modulus_signed(grid.0), position.y)
+            }
+            Direction::Right => {
+                GridPosition::new((position.x + 1).modulus_signed(grid.0), position.y)
+            }
+        }
+    }
+
+    pub fn moved(
+        &self,
+        direction: Direction,
+        grid: (i16, i16),
+        wall_mode: WallMode,
+    ) -> Option<GridPosition> {
+        if GridPosition::hits_wall(*self, direction, grid, wall_mode) {
+            None
+        } else {
+            Some(GridPosition::new_from_move(*self, direction, grid))
+        }
+    }
+
+    pub fn neighbors(&self, grid: (i16, i16)) -> [GridPosition; 4] {
+        Direction::ALL.map(|direction| GridPosition::new_from_move(*self, direction, grid))
+    }
+
+    pub fn manhattan_distance(&self, other: GridPosition) -> i16 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn toroidal_offset(&self, other: GridPosition, grid: (i16, i16)) -> (i16, i16) {
+        (
+            shortest_offset(other.x - self.x, grid.0),
+            shortest_offset(other.y - self.y, grid.1),
+        )
+    }
+
+    pub fn toroidal_distance(&self, other: GridPosition, grid: (i16, i16)) -> i16 {
+        let (dx, dy) = self.toroidal_offset(other, grid);
+
+        dx.abs() + dy.abs()
+    }
+
+    pub fn hits_wall(
+        position: GridPosition,
+        direction: Direction,
+        grid: (i16, i16),
+        wall_mode: WallMode,
+    ) -> bool {
+        wall_mode.is_solid(direction) && GridPosition::crosses_boundary(position, direction, grid)
+    }
+
+    pub fn crosses_boundary(
+        position: GridPosition,
+        direction: Direction,
+        grid: (i16, i16),
+    ) -> bool {
+        match direction {
+            Direction::Up => position.y == 0,
+            Direction::Down => position.y == grid.1 - 1,
+            Direction::Left => position.x == 0,
+            Direction::Right => position.x == grid.0 - 1,
+        }
+    }
+
+    pub fn render_positions(
+        &self,
+        to: GridPosition,
+        cell: (i16, i16),
+        grid: (i16, i16),
+        progress: f32,
+        wrap_duration: f32,
+    ) -> ([f32; 2], Option<[f32; 2]>) {
+        let origin = self.to_rect(cell);
+
+        // Portal jumps and standing still are drawn in place.
+        let (dx, dy) = match single_step(*self, to, grid) {
+            Some(step) => step,
+            None => return ([origin.x, origin.y], None),
+        };
+
+        let (width, height) = (cell.0 as f32, cell.1 as f32);
+
+        if *self + (dx, dy) == to {
+            let moved = [
+                origin.x + dx as f32 * width * progress,
+                origin.y + dy as f32 * height * progress,
+            ];
+
+            return (moved, None);
+        }
+
+        // A wrapping segment crosses during the last part of the tick, split
+        // across both edges; without that window it teleports at the end.
+        let start = 1.0 - wrap_duration.clamp(0.0, 1.0);
+        if progress <= start {
+            return ([origin.x, origin.y], None);
+        }
+
+        let progress = (progress - start) / (1.0 - start);
+        let moved = [
+            origin.x + dx as f32 * width * progress,
+            origin.y + dy as f32 * height * progress,
+        ];
+        let target = to.to_rect(cell);
+        let entering = [
+            target.x - dx as f32 * width * (1.0 - progress),
+            target.y - dy as f32 * height * (1.0 - progress),
+        ];
+
+        (moved, Some(entering))
+    }
+
+    pub fn to_rect(&self, cell: (i16, i16)) -> graphics::Rect {
+        graphics::Rect::new_i32(
+            self.x as i32 * cell.0 as i32,
+            self.y as i32 * cell.1 as i32,
+            cell.0 as i32,
+            cell.1 as i32,
+        )
+    }
+}
+
+impl Ord for GridPosition {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for GridPosition {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<(i16, i16)> for GridPosition {
+    type Output = GridPosition;
+
+    fn add(self, offset: (i16, i16)) -> Self::Output {
+        GridPosition::new(self.x + offset.0, self.y + offset.1)
+    }
+}
+
+impl From<(i16, i16)> for GridPosition {
+    fn from(position: (i16, i16)) -> Self {
+        GridPosition {
+            x: position.0,
+            y: position.1,
+        }
+    }
+}
+
+fn shortest_offset(offset: i16, size: i16) -> i16 {
+    if offset.abs() * 2 <= size {
+        offset
+    } else {
+        offset - offset.signum() * size
+    }
+}
+
+fn single_step(from: GridPosition, to: GridPosition, grid: (i16, i16)) -> Option<(i16, i16)> {
+    match (to.x - from.x, to.y - from.y) {
+        (dx, 0) if dx.abs() == 1 => Some((dx, 0)),
+        (0, dy) if dy.abs() == 1 => Some((0, dy)),
+        (dx, 0) if dx.abs() == grid.0 - 1 => Some((-dx.signum(), 0)),
+        (0, dy) if dy.abs() == grid.1 - 1 => Some((0, -dy.signum())),
+        _ => None,
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{AiStrategy, Game, GameConfig};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GameSummary {
+    pub score: u32,
+    pub length: usize,
+    pub died: bool,
+    pub ticks: u64,
+}
+
+pub fn run_headless(
+    mut config: GameConfig,
+    ticks: u64,
+    strategy: AiStrategy,
+) -> Result<GameSummary, String> {
+    config.ai_mode = true;
+    config.ai_strategy = strategy;
+    config.high_score_file = None;
+    config.tick_log_file = None;
+
+    let mut game = Game::try_new(config)?;
+
+    while game.tick() < ticks && !game.is_finished() {
+        game.step(None);
+    }
+
+    Ok(GameSummary {
+        score: game.score(),
+        length: game.player().length(),
+        died: game.is_game_over(),
+        ticks: game.tick(),
+    })
+}
@@ -0,0 +1,14 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub(crate) fn load(path: &Path) -> u32 {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| contents.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+pub(crate) fn save(path: &Path, high_score: u32) -> io::Result<()> {
+    fs::write(path, high_score.to_string())
+}
@@ -0,0 +1,93 @@
+use ggez::event::KeyCode;
+
+use std::collections::HashMap;
+
+use crate::{Direction, Game};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ControlScheme {
+    Absolute,
+    Relative,
+}
+
+impl ControlScheme {
+    pub fn resolve(&self, heading: Direction, input: Direction) -> Option<Direction> {
+        match (*self, input) {
+            (ControlScheme::Absolute, _) => Some(input),
+            (ControlScheme::Relative, Direction::Left) => Some(heading.turn_left()),
+            (ControlScheme::Relative, Direction::Right) => Some(heading.turn_right()),
+            (ControlScheme::Relative, Direction::Up | Direction::Down) => None,
+        }
+    }
+}
+
+/// Turns every player whose key map binds `keycode`, `key_maps[i]` steering
+/// player `i`. Auto-repeat events from a held key are dropped unless the
+/// config enables `key_repeat`, so holding a key only turns once.
+pub fn steer(game: &mut Game, key_maps: &[KeyMap], keycode: KeyCode, repeat: bool) {
+    if repeat && !game.config().key_repeat {
+        return;
+    }
+
+    let control_scheme = game.config().control_scheme;
+
+    for (index, key_map) in key_maps.iter().enumerate() {
+        let heading = match game.players().get(index) {
+            Some(player) => player.heading(),
+            None => continue,
+        };
+
+        if let Some(direction) = key_map
+            .direction(keycode)
+            .and_then(|input| control_scheme.resolve(heading, input))
+        {
+            game.turn_player(index, direction);
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyMap {
+    bindings: HashMap<KeyCode, Direction>,
+}
+
+impl KeyMap {
+    pub fn new() -> Self {
+        KeyMap {
+            bindings: HashMap::new(),
+        }
+    }
+
+    pub fn wasd() -> Self {
+        KeyMap::new()
+            .bind(KeyCode::W, Direction::Up)
+            .bind(KeyCode::S, Direction::Down)
+            .bind(KeyCode::A, Direction::Left)
+            .bind(KeyCode::D, Direction::Right)
+    }
+
+    pub fn arrows() -> Self {
+        KeyMap::new()
+            .bind(KeyCode::Up, Direction::Up)
+            .bind(KeyCode::Down, Direction::Down)
+            .bind(KeyCode::Left, Direction::Left)
+            .bind(KeyCode::Right, Direction::Right)
+    }
+
+    pub fn bind(mut self, key: KeyCode, direction: Direction) -> Self {
+        self.bindings.insert(key, direction);
+        self
+    }
+
+    pub fn direction(&self, key: KeyCode) -> Option<Direction> {
+        self.bindings.get(&key).copied()
+    }
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        let mut key_map = KeyMap::wasd();
+        key_map.bindings.extend(KeyMap::arrows().bindings);
+        key_map
+    }
+}
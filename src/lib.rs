@@ -1,366 +1,94 @@
-use ggez::event::EventHandler;
-use ggez::event::KeyCode;
-use ggez::{event, graphics, Context, GameError, GameResult};
-
-use std::collections::LinkedList;
-use std::thread::sleep;
-use std::time::{Duration, Instant};
-
-use rand::Rng;
+use ggez::{event, Context, GameResult};
+
+use std::env;
+use std::path::PathBuf;
+
+mod ai;
+mod builder;
+mod collision_rules;
+mod config;
+mod difficulty;
+mod direction;
+mod food;
+mod frame_clock;
+mod game;
+mod game_event;
+mod game_state;
+mod grid;
+mod headless;
+mod high_score;
+mod keyboard;
+mod meshes;
+mod mode;
+mod obstacles;
+mod outcome;
+mod player;
+mod power_up;
+mod replay;
+mod scoring;
+mod snapshot;
+mod sounds;
+mod theme;
+mod tick_log;
+
+pub use ai::AiStrategy;
+pub use builder::GameStateBuilder;
+pub use collision_rules::{CollisionRules, HeadOn};
+pub use config::GameConfig;
+pub use difficulty::Difficulty;
+pub use direction::Direction;
+pub use food::{Food, FoodKind};
+pub use frame_clock::FrameClock;
+pub use game::Game;
+pub use game_event::GameEvent;
+pub use game_state::GameState;
+pub use grid::{GridPosition, WallMode};
+pub use headless::{run_headless, GameSummary};
+pub use keyboard::{steer, ControlScheme, KeyMap};
+pub use mode::GameMode;
+pub use obstacles::ObstacleLayout;
+pub use outcome::StepOutcome;
+pub use player::{Collision, Player, Segment};
+pub use power_up::PowerUp;
+pub use replay::Replay;
+pub use scoring::{flat_scoring, ScoringRule};
+pub use snapshot::GameSnapshot;
+pub use theme::{BackgroundPattern, Theme};
+pub use tick_log::{TickLog, TickRecord};
 
 const GAME_TITLE: &str = "Snake Game";
 
-const GREEN: [f32; 4] = [0.0, 1.0, 0.0, 1.0];
-
-const GRID_SIZE: (i16, i16) = (25, 25);
-const GRID_CELL_SIZE: (i16, i16) = (25, 25);
-
-const SCREEN_SIZE: (u32, u32) = (
-    GRID_SIZE.0 as u32 * GRID_CELL_SIZE.0 as u32,
-    GRID_SIZE.1 as u32 * GRID_CELL_SIZE.1 as u32,
-);
-
-const FRAMES_PER_SECOND: f32 = 8.0;
-const MS_PER_FRAME: u64 = (1.0 / FRAMES_PER_SECOND * 1000.0) as u64;
-
-trait ModulusSigned {
-    fn modulus_signed(&self, n: Self) -> Self;
-}
-
-impl<T> ModulusSigned for T
-where
-    T: std::ops::Add<Output = T> + std::ops::Rem<Output = T> + Clone,
-{
-    fn modulus_signed(&self, n: T) -> T {
-        (self.clone() % n.clone() + n.clone()) % n
-    }
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn inverse(&self) -> Self {
-        match *self {
-            Direction::Up => Direction::Down,
-            Direction::Down => Direction::Up,
-            Direction::Left => Direction::Right,
-            Direction::Right => Direction::Left,
-        }
-    }
-}
-
-struct KeyboardListener {}
-
-impl KeyboardListener {
-    fn from_keycode(key: KeyCode) -> Option<Direction> {
-        match key {
-            KeyCode::Up | KeyCode::W => Some(Direction::Up),
-            KeyCode::Down | KeyCode::S => Some(Direction::Down),
-            KeyCode::Left | KeyCode::A => Some(Direction::Left),
-            KeyCode::Right | KeyCode::D => Some(Direction::Right),
-            _ => None,
-        }
-    }
-}
-
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-struct GridPosition {
-    x: i16,
-    y: i16,
-}
-
-impl GridPosition {
-    fn new(x: i16, y: i16) -> Self {
-        GridPosition { x, y }
-    }
-
-    fn random(max_x: i16, max_y: i16) -> Self {
-        let mut rng = rand::thread_rng();
-
-        (rng.gen_range(0..max_x), rng.gen_range(0..max_y)).into()
-    }
+fn build_context_and_event_loop(
+    config: &GameConfig,
+) -> GameResult<(Context, event::EventLoop<()>)> {
+    let (width, height) = config.screen_size();
 
-    fn new_from_move(position: GridPosition, direction: Direction) -> Self {
-        match direction {
-            Direction::Up => {
-                GridPosition::new(position.x, (position.y - 1).modulus_signed(GRID_SIZE.1))
-            }
-            Direction::Down => {
-                GridPosition::new(position.x, (position.y + 1).modulus_signed(GRID_SIZE.1))
-            }
-            Direction::Left => {
-                GridPosition::new((position.x - 1).modulus_signed(GRID_SIZE.0), position.y)
-            }
-            Direction::Right => {
-                GridPosition::new((position.x + 1).modulus_signed(GRID_SIZE.0), position.y)
-            }
-        }
+    let mut context = ggez::ContextBuilder::new(GAME_TITLE, "DevAles");
+    if let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") {
+        context = context.add_resource_path(PathBuf::from(manifest_dir).join("resources"));
     }
-}
-
-impl From<GridPosition> for graphics::Rect {
-    fn from(position: GridPosition) -> Self {
-        graphics::Rect::new_i32(
-            position.x as i32 * GRID_CELL_SIZE.0 as i32,
-            position.y as i32 * GRID_CELL_SIZE.1 as i32,
-            GRID_CELL_SIZE.0 as i32,
-            GRID_CELL_SIZE.1 as i32,
-        )
-    }
-}
-
-impl From<(i16, i16)> for GridPosition {
-    fn from(position: (i16, i16)) -> Self {
-        GridPosition {
-            x: position.0,
-            y: position.1,
-        }
-    }
-}
-
-#[derive(Clone, Copy, Debug)]
-struct Segment {
-    position: GridPosition,
-}
-
-impl Segment {
-    fn new(position: GridPosition) -> Self {
-        Segment { position }
-    }
-}
-
-struct Food {
-    position: GridPosition,
-}
-
-impl Food {
-    fn new(position: GridPosition) -> Self {
-        Food { position }
-    }
-
-    fn draw(&self, context: &mut Context) -> GameResult {
-        let mesh = graphics::MeshBuilder::new()
-            .rectangle(
-                graphics::DrawMode::fill(),
-                self.position.into(),
-                graphics::Color::new(0.0, 0.0, 1.0, 1.0),
-            )?
-            .build(context)?;
-
-        graphics::draw(context, &mesh, graphics::DrawParam::default())?;
-        Ok(())
-    }
-}
-
-#[derive(Clone, Copy, Debug)]
-enum Collision {
-    Food,
-    Itself,
-}
-
-struct Player {
-    head: Segment,
-    body: LinkedList<Segment>,
-    direction: Direction,
-    collision: Option<Collision>,
-    last_update_direction: Direction,
-}
-
-impl Player {
-    fn new(position: GridPosition) -> Self {
-        let mut body = LinkedList::new();
-        body.push_back(Segment::new((position.x - 1, position.y).into()));
 
-        Player {
-            head: Segment::new(position),
-            body,
-            direction: Direction::Right,
-            collision: None,
-            last_update_direction: Direction::Right,
-        }
-    }
-
-    fn eats(&self, food: &Food) -> bool {
-        self.head.position == food.position
-    }
-
-    fn collides_with_itself(&self) -> bool {
-        for segment in self.body.iter() {
-            if self.head.position == segment.position {
-                return true;
-            }
-        }
-        false
-    }
-
-    fn update(&mut self, food: &Food) {
-        let new_head_position = GridPosition::new_from_move(self.head.position, self.direction);
-        let new_head = Segment::new(new_head_position);
-
-        self.body.push_front(self.head);
-        self.head = new_head;
-
-        if self.collides_with_itself() {
-            self.collision = Some(Collision::Itself);
-        } else if self.eats(food) {
-            self.collision = Some(Collision::Food);
-        } else {
-            self.collision = None;
-        }
-
-        if self.collision.is_none() {
-            self.body.pop_back();
-        }
-
-        self.last_update_direction = self.direction;
-    }
-
-    fn draw(&self, context: &mut Context) -> GameResult {
-        for segment in self.body.iter() {
-            let mesh = graphics::MeshBuilder::new()
-                .rectangle(
-                    graphics::DrawMode::fill(),
-                    segment.position.into(),
-                    graphics::Color::new(1.0, 0.5, 0.0, 1.0),
-                )?
-                .build(context)?;
-            graphics::draw(context, &mesh, graphics::DrawParam::default())?;
-        }
-        let mesh = graphics::MeshBuilder::new()
-            .rectangle(
-                graphics::DrawMode::fill(),
-                self.head.position.into(),
-                graphics::Color::new(1.0, 0.0, 0.0, 1.0),
-            )?
-            .build(context)?;
-
-        graphics::draw(context, &mesh, graphics::DrawParam::default())?;
-        Ok(())
-    }
-}
-
-struct GameState {
-    player: Player,
-    food: Food,
-    game_over: bool,
-    last_update: Instant,
-}
-
-impl GameState {
-    fn new() -> GameResult<Self> {
-        let player_position = (GRID_SIZE.0 / 4, GRID_SIZE.1 / 2).into();
-        let food_position = GridPosition::random(GRID_SIZE.0, GRID_SIZE.1);
-
-        Ok(GameState {
-            player: Player::new(player_position),
-            food: Food::new(food_position),
-            game_over: false,
-            last_update: Instant::now(),
-        })
-    }
-
-    fn verify_collision(&mut self) {
-        if let Some(collision) = self.player.collision {
-            match collision {
-                Collision::Food => {
-                    let new_food_position = GridPosition::random(GRID_SIZE.0, GRID_SIZE.1);
-                    self.food.position = new_food_position;
-                }
-
-                Collision::Itself => {
-                    self.game_over = true;
-                }
-            }
-        }
-    }
-
-    fn verify_game_over(&mut self, context: &mut Context) -> GameResult {
-        if self.game_over {
-            self.draw_game_over(context)?;
-
-            sleep(Duration::from_millis(2000));
-            *self = GameState::new()?;
-        }
-
-        Ok(())
-    }
-
-    fn draw_game_over(&self, context: &mut Context) -> GameResult {
-        let text = graphics::Text::new(graphics::TextFragment {
-            text: format!("Game Over!\nScore: {}", self.player.body.len()),
-            font: Some(graphics::Font::default()),
-            color: Some(graphics::Color::new(1.0, 0.0, 0.0, 1.0)),
-            scale: Some(graphics::PxScale::from(50.0)),
-        });
-
-        graphics::draw(context, &text, graphics::DrawParam::default())?;
-        graphics::present(context)?;
-
-        Ok(())
-    }
-}
-
-impl EventHandler<GameError> for GameState {
-    fn update(&mut self, context: &mut Context) -> GameResult {
-        if Instant::now() - self.last_update < Duration::from_millis(MS_PER_FRAME) {
-            return Ok(());
-        }
-
-        self.verify_game_over(context)?;
-        self.player.update(&self.food);
-        self.verify_collision();
-
-        self.last_update = Instant::now();
-        Ok(())
-    }
-
-    fn draw(&mut self, context: &mut Context) -> GameResult {
-        graphics::clear(context, GREEN.into());
-
-        self.player.draw(context)?;
-        self.food.draw(context)?;
-
-        graphics::present(context)?;
-
-        ggez::timer::yield_now();
-
-        Ok(())
-    }
-
-    fn key_down_event(
-        &mut self,
-        _context: &mut Context,
-        keycode: KeyCode,
-        _keymods: event::KeyMods,
-        _repeat: bool,
-    ) {
-        if let Some(direction) = KeyboardListener::from_keycode(keycode) {
-            if direction.inverse() != self.player.last_update_direction {
-                self.player.direction = direction;
-            }
-        }
-    }
-}
-
-fn build_context_and_event_loop() -> GameResult<(Context, event::EventLoop<()>)> {
-    let context = ggez::ContextBuilder::new(GAME_TITLE, "DevAles");
     let window_setup = context.window_setup(ggez::conf::WindowSetup::default().title(GAME_TITLE));
     let new_game = window_setup.window_mode(
-        ggez::conf::WindowMode::default().dimensions(SCREEN_SIZE.0 as f32, SCREEN_SIZE.1 as f32),
+        ggez::conf::WindowMode::default()
+            .dimensions(width, height)
+            .resizable(true),
     );
 
     new_game.build()
 }
 
 pub fn run() -> GameResult {
-    let (context, event_loop) = build_context_and_event_loop()?;
+    run_with_difficulty(Difficulty::default())
+}
+
+pub fn run_with_difficulty(difficulty: Difficulty) -> GameResult {
+    run_with_config(GameConfig::from_difficulty(difficulty))
+}
+
+pub fn run_with_config(config: GameConfig) -> GameResult {
+    let (mut context, event_loop) = build_context_and_event_loop(&config)?;
 
-    let state = GameState::new()?;
+    let state = GameStateBuilder::from_config(config).build(&mut context)?;
     event::run(context, event_loop, state)
 }
@@ -0,0 +1,270 @@
+use ggez::{graphics, Context, GameResult};
+
+use crate::{BackgroundPattern, GameConfig, GridPosition, Theme};
+
+const GRID_LINE_WIDTH: f32 = 1.0;
+
+pub(crate) struct SnakeMeshes {
+    pub(crate) head: graphics::Mesh,
+    pub(crate) eye: graphics::Mesh,
+    pub(crate) eye_size: i16,
+    pub(crate) outline: Option<graphics::Mesh>,
+    pub(crate) body: graphics::MeshBatch,
+    pub(crate) body_color: graphics::Color,
+    pub(crate) tail_color: Option<graphics::Color>,
+}
+
+impl SnakeMeshes {
+    fn new(
+        context: &mut Context,
+        cell: (i16, i16),
+        theme: &Theme,
+        rival: bool,
+    ) -> GameResult<Self> {
+        let (head, body, tail) = if rival {
+            (theme.rival_head, theme.rival_body, theme.rival_tail)
+        } else {
+            (theme.head, theme.body, theme.tail)
+        };
+
+        let eye_size = (cell.0.min(cell.1) / 5).max(1);
+        let outline = if theme.shape_cues {
+            Some(outline_mesh(
+                context,
+                cell,
+                theme.corner_radius,
+                theme.text,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(SnakeMeshes {
+            head: rounded_mesh(context, cell, theme.corner_radius, head)?,
+            eye: cell_mesh(context, (eye_size, eye_size), theme.eye)?,
+            eye_size,
+            outline,
+            body: graphics::MeshBatch::new(rounded_mesh(
+                context,
+                cell,
+                theme.corner_radius,
+                graphics::Color::WHITE,
+            )?)?,
+            body_color: body,
+            tail_color: tail,
+        })
+    }
+}
+
+pub(crate) struct CellMeshes {
+    pub(crate) snakes: [SnakeMeshes; 2],
+    pub(crate) food: graphics::Mesh,
+    pub(crate) bonus_food: graphics::Mesh,
+    pub(crate) food_dot: Option<graphics::Mesh>,
+    pub(crate) food_highlight: Option<graphics::Mesh>,
+    pub(crate) wall_pass: graphics::Mesh,
+    pub(crate) slow_motion: graphics::Mesh,
+    pub(crate) obstacles: graphics::MeshBatch,
+    pub(crate) portal: graphics::Mesh,
+    pub(crate) grid_lines: Option<graphics::Mesh>,
+    pub(crate) checkerboard: Option<graphics::Mesh>,
+    pub(crate) background_tiles: Option<graphics::spritebatch::SpriteBatch>,
+}
+
+impl CellMeshes {
+    pub(crate) fn new(context: &mut Context, config: &GameConfig) -> GameResult<Self> {
+        let cell = config.cell;
+        let theme = &config.theme;
+        let food_dot = if theme.shape_cues {
+            Some(dot_mesh(context, cell, theme.text)?)
+        } else {
+            None
+        };
+        let food_highlight = if config.food_highlight {
+            Some(outline_mesh(
+                context,
+                cell,
+                theme.corner_radius,
+                graphics::Color::WHITE,
+            )?)
+        } else {
+            None
+        };
+        let grid_lines = if config.show_grid {
+            Some(grid_lines_mesh(context, config)?)
+        } else {
+            None
+        };
+
+        let checkerboard = match theme.pattern {
+            BackgroundPattern::Checkerboard(color) => {
+                Some(checkerboard_mesh(context, config, color)?)
+            }
+            BackgroundPattern::Solid | BackgroundPattern::Image(_) => None,
+        };
+        let background_tiles = match theme.pattern {
+            // A missing image falls back to the solid background color.
+            BackgroundPattern::Image(path) => graphics::Image::new(context, path)
+                .ok()
+                .map(|image| tiles(image, config)),
+            BackgroundPattern::Solid | BackgroundPattern::Checkerboard(_) => None,
+        };
+
+        Ok(CellMeshes {
+            snakes: [
+                SnakeMeshes::new(context, cell, theme, false)?,
+                SnakeMeshes::new(context, cell, theme, true)?,
+            ],
+            food: rounded_mesh(context, cell, theme.corner_radius, theme.food)?,
+            bonus_food: rounded_mesh(context, cell, theme.corner_radius, theme.bonus_food)?,
+            food_dot,
+            food_highlight,
+            wall_pass: rounded_mesh(context, cell, theme.corner_radius, theme.wall_pass)?,
+            slow_motion: rounded_mesh(context, cell, theme.corner_radius, theme.slow_motion)?,
+            obstacles: graphics::MeshBatch::new(cell_mesh(context, cell, theme.obstacle)?)?,
+            portal: cell_mesh(context, cell, theme.portal)?,
+            grid_lines,
+            checkerboard,
+            background_tiles,
+        })
+    }
+}
+
+fn cell_mesh(
+    context: &mut Context,
+    cell: (i16, i16),
+    color: graphics::Color,
+) -> GameResult<graphics::Mesh> {
+    let rect = graphics::Rect::new_i32(0, 0, cell.0 as i32, cell.1 as i32);
+
+    graphics::Mesh::new_rectangle(context, graphics::DrawMode::fill(), rect, color)
+}
+
+fn rounded_mesh(
+    context: &mut Context,
+    cell: (i16, i16),
+    radius: f32,
+    color: graphics::Color,
+) -> GameResult<graphics::Mesh> {
+    if radius <= 0.0 {
+        return cell_mesh(context, cell, color);
+    }
+
+    let rect = graphics::Rect::new_i32(0, 0, cell.0 as i32, cell.1 as i32);
+    let radius = radius.min(cell.0.min(cell.1) as f32 / 2.0);
+
+    graphics::Mesh::new_rounded_rectangle(context, graphics::DrawMode::fill(), rect, radius, color)
+}
+
+fn outline_mesh(
+    context: &mut Context,
+    cell: (i16, i16),
+    radius: f32,
+    color: graphics::Color,
+) -> GameResult<graphics::Mesh> {
+    let width = (cell.0.min(cell.1) / 8).max(1) as f32;
+    let rect = graphics::Rect::new(
+        width / 2.0,
+        width / 2.0,
+        cell.0 as f32 - width,
+        cell.1 as f32 - width,
+    );
+
+    if radius <= 0.0 {
+        return graphics::Mesh::new_rectangle(
+            context,
+            graphics::DrawMode::stroke(width),
+            rect,
+            color,
+        );
+    }
+
+    let radius = radius.min(rect.w.min(rect.h) / 2.0);
+
+    graphics::Mesh::new_rounded_rectangle(
+        context,
+        graphics::DrawMode::stroke(width),
+        rect,
+        radius,
+        color,
+    )
+}
+
+fn dot_mesh(
+    context: &mut Context,
+    cell: (i16, i16),
+    color: graphics::Color,
+) -> GameResult<graphics::Mesh> {
+    let size = (cell.0.min(cell.1) / 3).max(1) as f32;
+    let rect = graphics::Rect::new(
+        (cell.0 as f32 - size) / 2.0,
+        (cell.1 as f32 - size) / 2.0,
+        size,
+        size,
+    );
+
+    graphics::Mesh::new_rectangle(context, graphics::DrawMode::fill(), rect, color)
+}
+
+fn grid_lines_mesh(context: &mut Context, config: &GameConfig) -> GameResult<graphics::Mesh> {
+    let (width, height) = config.screen_size();
+    let mut builder = graphics::MeshBuilder::new();
+
+    for x in 1..config.grid.0 {
+        let x = x as f32 * config.cell.0 as f32;
+        builder.line(
+            &[[x, 0.0], [x, height]],
+            GRID_LINE_WIDTH,
+            config.theme.grid_lines,
+        )?;
+    }
+
+    for y in 1..config.grid.1 {
+        let y = y as f32 * config.cell.1 as f32;
+        builder.line(
+            &[[0.0, y], [width, y]],
+            GRID_LINE_WIDTH,
+            config.theme.grid_lines,
+        )?;
+    }
+
+    builder.build(context)
+}
+
+fn checkerboard_mesh(
+    context: &mut Context,
+    config: &GameConfig,
+    color: graphics::Color,
+) -> GameResult<graphics::Mesh> {
+    let mut builder = graphics::MeshBuilder::new();
+
+    for y in 0..config.grid.1 {
+        for x in (y % 2..config.grid.0).step_by(2) {
+            builder.rectangle(
+                graphics::DrawMode::fill(),
+                GridPosition::new(x, y).to_rect(config.cell),
+                color,
+            )?;
+        }
+    }
+
+    builder.build(context)
+}
+
+fn tiles(image: graphics::Image, config: &GameConfig) -> graphics::spritebatch::SpriteBatch {
+    let (width, height) = config.screen_size();
+    let (tile_width, tile_height) = (image.width().max(1) as f32, image.height().max(1) as f32);
+    let mut batch = graphics::spritebatch::SpriteBatch::new(image);
+
+    let mut y = 0.0;
+    while y < height {
+        let mut x = 0.0;
+        while x < width {
+            batch.add(graphics::DrawParam::default().dest([x, y]));
+            x += tile_width;
+        }
+        y += tile_height;
+    }
+
+    batch
+}
@@ -0,0 +1,11 @@
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GameMode {
+    #[default]
+    Classic,
+    TimeAttack {
+        limit: Duration,
+    },
+    Practice,
+}
@@ -0,0 +1,47 @@
+use crate::GridPosition;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ObstacleLayout {
+    Empty,
+    Border,
+    Pillars,
+    Custom(Vec<GridPosition>),
+}
+
+impl ObstacleLayout {
+    pub fn positions(&self, grid: (i16, i16)) -> Vec<GridPosition> {
+        match self {
+            ObstacleLayout::Empty => Vec::new(),
+            ObstacleLayout::Border => border(grid),
+            ObstacleLayout::Pillars => pillars(grid),
+            ObstacleLayout::Custom(positions) => positions.clone(),
+        }
+    }
+}
+
+fn pillars(grid: (i16, i16)) -> Vec<GridPosition> {
+    let corners = [
+        (grid.0 / 4, grid.1 / 4),
+        (grid.0 - 2 - grid.0 / 4, grid.1 / 4),
+        (grid.0 / 4, grid.1 - 2 - grid.1 / 4),
+        (grid.0 - 2 - grid.0 / 4, grid.1 - 2 - grid.1 / 4),
+    ];
+
+    corners
+        .into_iter()
+        .map(GridPosition::from)
+        .flat_map(|corner| [corner, corner + (1, 0), corner + (0, 1), corner + (1, 1)])
+        .collect()
+}
+
+fn border(grid: (i16, i16)) -> Vec<GridPosition> {
+    (0..grid.1)
+        .flat_map(|y| (0..grid.0).map(move |x| GridPosition::new(x, y)))
+        .filter(|position| {
+            position.x == 0
+                || position.y == 0
+                || position.x == grid.0 - 1
+                || position.y == grid.1 - 1
+        })
+        .collect()
+}
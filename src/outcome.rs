@@ -0,0 +1,11 @@
+use crate::Collision;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepOutcome {
+    Moved,
+    Ate,
+    Died(Collision),
+    TimeUp,
+    Won,
+    Skipped,
+}
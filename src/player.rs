@@ -0,0 +1,403 @@
+use ggez::{graphics, Context, GameResult};
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+
+use crate::meshes::SnakeMeshes;
+use crate::theme;
+use crate::{CollisionRules, Direction, Food, GameConfig, GameMode, GridPosition, WallMode};
+
+const MAX_QUEUED_TURNS: usize = 2;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment {
+    pub position: GridPosition,
+}
+
+impl Segment {
+    pub fn new(position: GridPosition) -> Self {
+        Segment { position }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Collision {
+    Food,
+    Itself,
+    Wall,
+    Obstacle,
+    Opponent,
+    Starved,
+}
+
+impl Collision {
+    pub fn is_fatal(&self) -> bool {
+        match *self {
+            Collision::Food => false,
+            Collision::Itself
+            | Collision::Wall
+            | Collision::Obstacle
+            | Collision::Opponent
+            | Collision::Starved => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Player {
+    pub head: Segment,
+    body: VecDeque<Segment>,
+    pub direction: Direction,
+    pub collision: Option<Collision>,
+    pub last_update_direction: Direction,
+    pub queued_turns: VecDeque<Direction>,
+    pub edge_warning: Option<Direction>,
+    pub wall_pass: u64,
+    pub grace: u64,
+    pub pending_growth: usize,
+    pub trail: VecDeque<GridPosition>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    occupied: HashMap<GridPosition, usize>,
+}
+
+impl Player {
+    pub fn new(position: GridPosition) -> Self {
+        Player::with_length(position, 2)
+    }
+
+    pub fn with_length(position: GridPosition, length: usize) -> Self {
+        let grid = (position.x + 1, position.y + 1);
+
+        Player::spawn(position, Direction::Right, length, grid)
+    }
+
+    pub fn spawn(
+        position: GridPosition,
+        direction: Direction,
+        length: usize,
+        grid: (i16, i16),
+    ) -> Self {
+        let (room_behind, step) = match direction {
+            Direction::Up => (grid.1 - 1 - position.y, (0, 1)),
+            Direction::Down => (position.y, (0, -1)),
+            Direction::Left => (grid.0 - 1 - position.x, (1, 0)),
+            Direction::Right => (position.x, (-1, 0)),
+        };
+        let length = length.clamp(1, room_behind.max(0) as usize + 1);
+
+        let body: VecDeque<Segment> = (1..length as i16)
+            .map(|offset| {
+                Segment::new((position.x + step.0 * offset, position.y + step.1 * offset).into())
+            })
+            .collect();
+        let occupied = count_cells(&body);
+
+        Player {
+            head: Segment::new(position),
+            body,
+            direction,
+            collision: None,
+            last_update_direction: direction,
+            queued_turns: VecDeque::with_capacity(MAX_QUEUED_TURNS),
+            edge_warning: None,
+            wall_pass: 0,
+            grace: 0,
+            pending_growth: 0,
+            trail: VecDeque::new(),
+            occupied,
+        }
+    }
+
+    pub fn body(&self) -> &VecDeque<Segment> {
+        &self.body
+    }
+
+    /// Body cells mapped to how many segments cover them, which can be more
+    /// than one while self-collision is off.
+    pub fn occupied(&self) -> &HashMap<GridPosition, usize> {
+        &self.occupied
+    }
+
+    pub(crate) fn reindex(&mut self) {
+        self.occupied = count_cells(&self.body);
+    }
+
+    fn push_front(&mut self, segment: Segment) {
+        *self.occupied.entry(segment.position).or_insert(0) += 1;
+        self.body.push_front(segment);
+    }
+
+    fn pop_back(&mut self) {
+        if let Some(segment) = self.body.pop_back() {
+            if let Entry::Occupied(mut count) = self.occupied.entry(segment.position) {
+                *count.get_mut() -= 1;
+                if *count.get() == 0 {
+                    count.remove();
+                }
+            }
+        }
+    }
+
+    pub fn length(&self) -> usize {
+        self.body.len() + 1
+    }
+
+    pub fn eats(&self, food: &Food) -> bool {
+        self.head.position == food.position
+    }
+
+    pub fn heading(&self) -> Direction {
+        self.queued_turns
+            .back()
+            .copied()
+            .unwrap_or(self.last_update_direction)
+    }
+
+    pub fn turn(&mut self, direction: Direction) {
+        if self.queued_turns.len() >= MAX_QUEUED_TURNS {
+            return;
+        }
+
+        let previous = self.heading();
+
+        if direction == previous || direction.inverse() == previous {
+            return;
+        }
+
+        self.queued_turns.push_back(direction);
+    }
+
+    pub fn occupies(&self, position: GridPosition) -> bool {
+        self.head.position == position || self.occupied.contains_key(&position)
+    }
+
+    pub fn collides_with_itself(&self) -> bool {
+        self.occupied.contains_key(&self.head.position)
+    }
+
+    /// Simulates moving one cell in `direction` under the same rules as
+    /// `update`, judging opponents by where they stand now.
+    pub fn would_die(
+        &self,
+        direction: Direction,
+        foods: &[Food],
+        obstacles: &[GridPosition],
+        config: &GameConfig,
+        wall_mode: WallMode,
+        opponents: &[&Player],
+    ) -> bool {
+        let mut moved = self.clone();
+        moved.queued_turns = VecDeque::from([direction]);
+        moved.update(foods, obstacles, config, wall_mode);
+
+        moved
+            .collision
+            .is_some_and(|collision| collision.is_fatal())
+            || opponents.iter().any(|opponent| {
+                config
+                    .collision_rules
+                    .check_opponent(&moved, opponent)
+                    .is_some()
+            })
+    }
+
+    pub(crate) fn remember_head(&mut self, limit: usize) {
+        if limit == 0 {
+            return;
+        }
+
+        if self.trail.len() >= limit {
+            self.trail.pop_back();
+        }
+        self.trail.push_front(self.head.position);
+    }
+
+    pub fn grow(&mut self, segments: usize) {
+        self.pending_growth += segments;
+    }
+
+    pub fn starve(&mut self, min_length: usize) {
+        if self.length() <= min_length.max(1) {
+            self.collision = Some(Collision::Starved);
+        } else {
+            self.pop_back();
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        foods: &[Food],
+        obstacles: &[GridPosition],
+        config: &GameConfig,
+        wall_mode: WallMode,
+    ) {
+        let grid = config.grid;
+        let (wall_mode, rules) = match config.mode {
+            GameMode::Practice => (
+                WallMode::Wrap,
+                CollisionRules {
+                    self_collision: false,
+                    ..config.collision_rules
+                },
+            ),
+            _ => (wall_mode, config.collision_rules),
+        };
+
+        if let Some(direction) = self.queued_turns.pop_front() {
+            self.direction = direction;
+        }
+
+        let mut new_head_position = match self.head.position.moved(self.direction, grid, wall_mode)
+        {
+            Some(position) => position,
+            None => {
+                self.collision = Some(Collision::Wall);
+                self.last_update_direction = self.direction;
+                return;
+            }
+        };
+
+        match config.portals {
+            Some((entry, exit)) if new_head_position == entry => new_head_position = exit,
+            Some((exit, entry)) if new_head_position == entry => new_head_position = exit,
+            _ => {}
+        }
+
+        let new_head = Segment::new(new_head_position);
+        let grows = foods.iter().any(|food| food.position == new_head_position);
+        let mut vacated = None;
+
+        if grows {
+            self.grow(config.growth_per_food);
+        }
+
+        if self.pending_growth > 0 {
+            self.pending_growth -= 1;
+        } else {
+            vacated = self.body.back().map(|segment| segment.position);
+            self.pop_back();
+        }
+
+        self.push_front(self.head);
+        self.head = new_head;
+
+        self.collision = rules.check(self, vacated, obstacles, grows);
+        self.grace = self.grace.saturating_sub(1);
+
+        self.last_update_direction = self.direction;
+        self.edge_warning = Some(self.direction).filter(|direction| {
+            GridPosition::crosses_boundary(self.head.position, *direction, grid)
+        });
+    }
+
+    pub(crate) fn draw(
+        &self,
+        context: &mut Context,
+        meshes: &mut SnakeMeshes,
+        config: &GameConfig,
+        progress: f32,
+    ) -> GameResult {
+        let (cell, grid) = (config.cell, config.grid);
+        let direction = self.queued_turns.front().copied().unwrap_or(self.direction);
+        let wall_mode = if self.wall_pass > 0 || config.mode == GameMode::Practice {
+            WallMode::Wrap
+        } else {
+            config.wall_mode
+        };
+        let next_head = match self.collision {
+            Some(collision) if collision.is_fatal() => self.head.position,
+            _ => self
+                .head
+                .position
+                .moved(direction, grid, wall_mode)
+                .unwrap_or(self.head.position),
+        };
+        let ahead = std::iter::once(next_head)
+            .chain(std::iter::once(self.head.position))
+            .chain(self.body.iter().map(|segment| segment.position));
+
+        let trail_length = self.trail.len() as f32;
+        for (index, position) in self.trail.iter().enumerate() {
+            let alpha = 0.5 * (1.0 - index as f32 / trail_length);
+
+            graphics::draw(
+                context,
+                &meshes.head,
+                graphics::DrawParam::default()
+                    .dest(position.to_rect(cell).point())
+                    .color(graphics::Color::new(1.0, 1.0, 1.0, alpha)),
+            )?;
+        }
+
+        let last = self.body.len().saturating_sub(1).max(1) as f32;
+
+        meshes.body.clear();
+        for (index, (segment, next)) in self.body.iter().zip(ahead.skip(1)).enumerate() {
+            let color = match meshes.tail_color {
+                Some(tail) => theme::lerp(meshes.body_color, tail, index as f32 / last),
+                None => meshes.body_color,
+            };
+
+            let (moved, entering) =
+                segment
+                    .position
+                    .render_positions(next, cell, grid, progress, config.wrap_duration);
+            for dest in std::iter::once(moved).chain(entering) {
+                meshes
+                    .body
+                    .add(graphics::DrawParam::default().dest(dest).color(color));
+            }
+        }
+        meshes.body.draw(context, graphics::DrawParam::default())?;
+
+        let near = meshes.eye_size;
+        let (right, bottom) = (cell.0 - 2 * near, cell.1 - 2 * near);
+        let eyes = match self.heading() {
+            Direction::Up => [(near, near), (right, near)],
+            Direction::Down => [(near, bottom), (right, bottom)],
+            Direction::Left => [(near, near), (near, bottom)],
+            Direction::Right => [(right, near), (right, bottom)],
+        };
+
+        let (moved, entering) = self.head.position.render_positions(
+            next_head,
+            cell,
+            grid,
+            progress,
+            config.wrap_duration,
+        );
+        for head in std::iter::once(moved).chain(entering) {
+            graphics::draw(
+                context,
+                &meshes.head,
+                graphics::DrawParam::default().dest(head),
+            )?;
+
+            if let Some(outline) = &meshes.outline {
+                graphics::draw(context, outline, graphics::DrawParam::default().dest(head))?;
+            }
+
+            for (x, y) in eyes {
+                graphics::draw(
+                    context,
+                    &meshes.eye,
+                    graphics::DrawParam::default().dest([head[0] + x as f32, head[1] + y as f32]),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn count_cells(body: &VecDeque<Segment>) -> HashMap<GridPosition, usize> {
+    let mut counts = HashMap::new();
+    for segment in body {
+        *counts.entry(segment.position).or_insert(0) += 1;
+    }
+
+    counts
+}
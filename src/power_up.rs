@@ -0,0 +1,6 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerUp {
+    WallPass,
+    SlowMotion,
+}
@@ -0,0 +1,568 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::{
+    AiStrategy, Direction, Game, GameConfig, GameMode, GridPosition, HeadOn, ObstacleLayout,
+    WallMode,
+};
+
+const REPLAY_MAGIC: &str = "snake-replay";
+const REPLAY_VERSION: u32 = 2;
+const INPUTS: &str = "inputs";
+
+#[derive(Clone, Debug)]
+pub struct Replay {
+    pub seed: u64,
+    pub config: GameConfig,
+    pub directions: Vec<Vec<Direction>>,
+}
+
+impl Replay {
+    pub fn new(seed: u64, config: &GameConfig) -> Self {
+        Replay {
+            seed,
+            config: config.clone(),
+            directions: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, directions: Vec<Direction>) {
+        self.directions.push(directions);
+    }
+
+    /// Applies the recorded gameplay settings on top of `config`, which only
+    /// keeps its presentation and tooling settings and its scoring rule.
+    pub fn configure(&self, config: GameConfig) -> GameConfig {
+        GameConfig {
+            seed: Some(self.seed),
+            cell: config.cell,
+            high_score_file: config.high_score_file,
+            show_grid: config.show_grid,
+            show_debug: config.show_debug,
+            smooth_movement: config.smooth_movement,
+            wrap_duration: config.wrap_duration,
+            trail_length: config.trail_length,
+            edge_warning: config.edge_warning,
+            food_highlight: config.food_highlight,
+            scoring: config.scoring,
+            theme: config.theme,
+            muted: config.muted,
+            confirm_quit: config.confirm_quit,
+            pause_on_focus_loss: config.pause_on_focus_loss,
+            resume_on_focus: config.resume_on_focus,
+            control_scheme: config.control_scheme,
+            key_repeat: config.key_repeat,
+            undo_depth: config.undo_depth,
+            step_debug: config.step_debug,
+            tick_log_file: config.tick_log_file,
+            tick_log_length: config.tick_log_length,
+            title_screen: config.title_screen,
+            countdown: config.countdown,
+            countdown_interval: config.countdown_interval,
+            ..self.config.clone()
+        }
+    }
+
+    pub fn play(&self, config: GameConfig) -> Result<Game, String> {
+        let mut game = Game::try_new(self.configure(config))?;
+        for directions in self.directions.iter() {
+            game.turn_all(directions);
+            game.step(None);
+        }
+
+        Ok(game)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = format!("{} {}\nseed {}\n", REPLAY_MAGIC, REPLAY_VERSION, self.seed);
+        for field in FIELDS {
+            contents.push_str(&format!("{} {}\n", field.name, (field.write)(&self.config)));
+        }
+        contents.push_str(INPUTS);
+        contents.push('\n');
+
+        let players = self.directions.first().map_or(0, Vec::len);
+        for player in 0..players {
+            contents.extend(
+                self.directions
+                    .iter()
+                    .filter_map(|directions| directions.get(player))
+                    .map(direction_to_char),
+            );
+            contents.push('\n');
+        }
+
+        fs::write(path, contents)
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let mut lines = contents.lines();
+
+        let version = lines
+            .next()
+            .and_then(|line| line.trim().strip_prefix(REPLAY_MAGIC))
+            .and_then(|version| version.trim().parse::<u32>().ok())
+            .ok_or_else(|| invalid_data("missing replay header".to_string()))?;
+
+        if version != REPLAY_VERSION {
+            return Err(invalid_data(format!(
+                "unsupported replay version {} (expected {})",
+                version, REPLAY_VERSION
+            )));
+        }
+
+        let seed = field(lines.next(), "seed")
+            .and_then(|seed| seed.parse().ok())
+            .ok_or_else(|| invalid_data("missing or malformed replay seed".to_string()))?;
+
+        let header: HashMap<&str, &str> = lines
+            .by_ref()
+            .take_while(|line| line.trim() != INPUTS)
+            .filter_map(|line| line.split_once(' '))
+            .collect();
+
+        let mut config = GameConfig::default();
+        for field in FIELDS {
+            header
+                .get(field.name)
+                .and_then(|value| (field.read)(&mut config, value))
+                .ok_or_else(|| {
+                    invalid_data(format!("missing or malformed replay {}", field.name))
+                })?;
+        }
+        config
+            .validate()
+            .map_err(|error| invalid_data(format!("invalid replay config: {}", error)))?;
+
+        let streams = lines
+            .map(|line| {
+                line.trim()
+                    .chars()
+                    .map(|character| {
+                        direction_from_char(character)
+                            .ok_or_else(|| invalid_data("malformed replay direction".to_string()))
+                    })
+                    .collect::<io::Result<Vec<_>>>()
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let ticks = streams.first().map_or(0, Vec::len);
+        if streams.iter().any(|stream| stream.len() != ticks) {
+            return Err(invalid_data(
+                "replay input streams have different lengths".to_string(),
+            ));
+        }
+
+        let directions = (0..ticks)
+            .map(|tick| streams.iter().map(|stream| stream[tick]).collect())
+            .collect();
+
+        Ok(Replay {
+            seed,
+            config,
+            directions,
+        })
+    }
+}
+
+/// A gameplay setting stored in the replay header as `name value`.
+struct Field {
+    name: &'static str,
+    write: fn(&GameConfig) -> String,
+    read: fn(&mut GameConfig, &str) -> Option<()>,
+}
+
+const FIELDS: &[Field] = &[
+    Field {
+        name: "grid",
+        write: |config| format!("{} {}", config.grid.0, config.grid.1),
+        read: |config, value| {
+            let (width, height) = value.split_once(' ')?;
+            config.grid = (width.parse().ok()?, height.parse().ok()?);
+            Some(())
+        },
+    },
+    Field {
+        name: "walls",
+        write: |config| wall_mode_to_str(config.wall_mode).to_string(),
+        read: |config, value| {
+            config.wall_mode = wall_mode_from_str(value)?;
+            Some(())
+        },
+    },
+    Field {
+        name: "mode",
+        write: |config| match config.mode {
+            GameMode::Classic => "classic".to_string(),
+            GameMode::Practice => "practice".to_string(),
+            GameMode::TimeAttack { limit } => format!("time-attack {}", duration_to_string(limit)),
+        },
+        read: |config, value| {
+            config.mode = match value {
+                "classic" => GameMode::Classic,
+                "practice" => GameMode::Practice,
+                _ => GameMode::TimeAttack {
+                    limit: duration_from_str(value.strip_prefix("time-attack ")?)?,
+                },
+            };
+            Some(())
+        },
+    },
+    Field {
+        name: "fps",
+        write: |config| config.frames_per_second.to_string(),
+        read: |config, value| {
+            config.frames_per_second = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "speed-up",
+        write: |config| duration_to_string(config.speed_up_per_food),
+        read: |config, value| {
+            config.speed_up_per_food = duration_from_str(value)?;
+            Some(())
+        },
+    },
+    Field {
+        name: "min-interval",
+        write: |config| duration_to_string(config.min_frame_interval),
+        read: |config, value| {
+            config.min_frame_interval = duration_from_str(value)?;
+            Some(())
+        },
+    },
+    Field {
+        name: "food-count",
+        write: |config| config.food_count.to_string(),
+        read: |config, value| {
+            config.food_count = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "food-sequence",
+        write: |config| {
+            optional(config.food_sequence.as_deref(), |cells| {
+                positions_to_string(cells)
+            })
+        },
+        read: |config, value| {
+            config.food_sequence = parse_optional(value, positions_from_str)?;
+            Some(())
+        },
+    },
+    Field {
+        name: "cycle-food",
+        write: |config| config.cycle_food_sequence.to_string(),
+        read: |config, value| {
+            config.cycle_food_sequence = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "bonus-chance",
+        write: |config| config.bonus_food_chance.to_string(),
+        read: |config, value| {
+            config.bonus_food_chance = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "bonus-lifetime",
+        write: |config| duration_to_string(config.bonus_food_lifetime),
+        read: |config, value| {
+            config.bonus_food_lifetime = duration_from_str(value)?;
+            Some(())
+        },
+    },
+    Field {
+        name: "power-up-chance",
+        write: |config| config.power_up_chance.to_string(),
+        read: |config, value| {
+            config.power_up_chance = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "wall-pass",
+        write: |config| duration_to_string(config.wall_pass_duration),
+        read: |config, value| {
+            config.wall_pass_duration = duration_from_str(value)?;
+            Some(())
+        },
+    },
+    Field {
+        name: "slow-motion",
+        write: |config| duration_to_string(config.slow_motion_duration),
+        read: |config, value| {
+            config.slow_motion_duration = duration_from_str(value)?;
+            Some(())
+        },
+    },
+    Field {
+        name: "hunger",
+        write: |config| optional(config.hunger_interval, duration_to_string),
+        read: |config, value| {
+            config.hunger_interval = parse_optional(value, duration_from_str)?;
+            Some(())
+        },
+    },
+    Field {
+        name: "shrink",
+        write: |config| optional(config.shrink_interval, duration_to_string),
+        read: |config, value| {
+            config.shrink_interval = parse_optional(value, duration_from_str)?;
+            Some(())
+        },
+    },
+    Field {
+        name: "obstacles",
+        write: |config| match &config.obstacles {
+            ObstacleLayout::Empty => "empty".to_string(),
+            ObstacleLayout::Border => "border".to_string(),
+            ObstacleLayout::Pillars => "pillars".to_string(),
+            ObstacleLayout::Custom(cells) => format!("custom {}", positions_to_string(cells)),
+        },
+        read: |config, value| {
+            config.obstacles = match value {
+                "empty" => ObstacleLayout::Empty,
+                "border" => ObstacleLayout::Border,
+                "pillars" => ObstacleLayout::Pillars,
+                _ => ObstacleLayout::Custom(positions_from_str(value.strip_prefix("custom ")?)?),
+            };
+            Some(())
+        },
+    },
+    Field {
+        name: "portals",
+        write: |config| {
+            optional(config.portals, |(entry, exit)| {
+                positions_to_string(&[entry, exit])
+            })
+        },
+        read: |config, value| {
+            config.portals = parse_optional(value, |value| match positions_from_str(value)?[..] {
+                [entry, exit] => Some((entry, exit)),
+                _ => None,
+            })?;
+            Some(())
+        },
+    },
+    Field {
+        name: "spawn",
+        write: |config| optional(config.spawn, |spawn| positions_to_string(&[spawn])),
+        read: |config, value| {
+            config.spawn = parse_optional(value, position_from_str)?;
+            Some(())
+        },
+    },
+    Field {
+        name: "spawn-direction",
+        write: |config| direction_to_char(&config.spawn_direction).to_string(),
+        read: |config, value| {
+            let mut characters = value.chars();
+            config.spawn_direction = direction_from_char(characters.next()?)?;
+            characters.next().is_none().then_some(())
+        },
+    },
+    Field {
+        name: "initial-length",
+        write: |config| config.initial_length.to_string(),
+        read: |config, value| {
+            config.initial_length = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "min-length",
+        write: |config| config.min_length.to_string(),
+        read: |config, value| {
+            config.min_length = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "growth",
+        write: |config| config.growth_per_food.to_string(),
+        read: |config, value| {
+            config.growth_per_food = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "droppings",
+        write: |config| config.droppings.to_string(),
+        read: |config, value| {
+            config.droppings = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "reach-length",
+        write: |config| optional(config.reach_length, |length| length.to_string()),
+        read: |config, value| {
+            config.reach_length = parse_optional(value, |value| value.parse().ok())?;
+            Some(())
+        },
+    },
+    Field {
+        name: "two-player",
+        write: |config| config.two_player.to_string(),
+        read: |config, value| {
+            config.two_player = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "assist",
+        write: |config| config.assist.to_string(),
+        read: |config, value| {
+            config.assist = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "grace",
+        write: |config| config.self_collision_grace.to_string(),
+        read: |config, value| {
+            config.self_collision_grace = value.parse().ok()?;
+            Some(())
+        },
+    },
+    Field {
+        name: "collisions",
+        write: |config| {
+            let rules = config.collision_rules;
+            format!(
+                "{} {} {} {}",
+                rules.self_collision,
+                rules.tail_pass,
+                rules.opponent_collision,
+                match rules.head_on {
+                    HeadOn::Draw => "draw",
+                    HeadOn::Longest => "longest",
+                }
+            )
+        },
+        read: |config, value| {
+            let mut parts = value.split(' ');
+            let rules = &mut config.collision_rules;
+            rules.self_collision = parts.next()?.parse().ok()?;
+            rules.tail_pass = parts.next()?.parse().ok()?;
+            rules.opponent_collision = parts.next()?.parse().ok()?;
+            rules.head_on = match parts.next()? {
+                "draw" => HeadOn::Draw,
+                "longest" => HeadOn::Longest,
+                _ => return None,
+            };
+            parts.next().is_none().then_some(())
+        },
+    },
+    Field {
+        name: "ai",
+        write: |config| {
+            let strategy = match config.ai_strategy {
+                AiStrategy::Greedy => "greedy",
+                AiStrategy::Bfs => "bfs",
+            };
+            format!("{} {}", config.ai_mode, strategy)
+        },
+        read: |config, value| {
+            let (mode, strategy) = value.split_once(' ')?;
+            config.ai_mode = mode.parse().ok()?;
+            config.ai_strategy = match strategy {
+                "greedy" => AiStrategy::Greedy,
+                "bfs" => AiStrategy::Bfs,
+                _ => return None,
+            };
+            Some(())
+        },
+    },
+];
+
+fn optional<T>(value: Option<T>, write: impl FnOnce(T) -> String) -> String {
+    value.map_or_else(|| "-".to_string(), write)
+}
+
+fn parse_optional<T>(value: &str, read: impl FnOnce(&str) -> Option<T>) -> Option<Option<T>> {
+    match value {
+        "-" => Some(None),
+        value => read(value).map(Some),
+    }
+}
+
+fn duration_to_string(duration: Duration) -> String {
+    duration.as_micros().to_string()
+}
+
+fn duration_from_str(value: &str) -> Option<Duration> {
+    value.parse().ok().map(Duration::from_micros)
+}
+
+fn positions_to_string(positions: &[GridPosition]) -> String {
+    positions
+        .iter()
+        .map(|position| format!("{},{}", position.x, position.y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn position_from_str(value: &str) -> Option<GridPosition> {
+    let (x, y) = value.split_once(',')?;
+    Some(GridPosition::new(x.parse().ok()?, y.parse().ok()?))
+}
+
+fn positions_from_str(value: &str) -> Option<Vec<GridPosition>> {
+    value.split_whitespace().map(position_from_str).collect()
+}
+
+fn field<'a>(line: Option<&'a str>, name: &str) -> Option<&'a str> {
+    line?.trim().strip_prefix(name)?.strip_prefix(' ')
+}
+
+fn wall_mode_to_str(wall_mode: WallMode) -> &'static str {
+    match wall_mode {
+        WallMode::Wrap => "wrap",
+        WallMode::Solid => "solid",
+        WallMode::WrapHorizontal => "wrap-horizontal",
+        WallMode::WrapVertical => "wrap-vertical",
+    }
+}
+
+fn wall_mode_from_str(wall_mode: &str) -> Option<WallMode> {
+    match wall_mode {
+        "wrap" => Some(WallMode::Wrap),
+        "solid" => Some(WallMode::Solid),
+        "wrap-horizontal" => Some(WallMode::WrapHorizontal),
+        "wrap-vertical" => Some(WallMode::WrapVertical),
+        _ => None,
+    }
+}
+
+fn direction_to_char(direction: &Direction) -> char {
+    match *direction {
+        Direction::Up => 'U',
+        Direction::Down => 'D',
+        Direction::Left => 'L',
+        Direction::Right => 'R',
+    }
+}
+
+fn direction_from_char(character: char) -> Option<Direction> {
+    match character {
+        'U' => Some(Direction::Up),
+        'D' => Some(Direction::Down),
+        'L' => Some(Direction::Left),
+        'R' => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
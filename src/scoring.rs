@@ -0,0 +1,7 @@
+use std::time::Duration;
+
+pub type ScoringRule = fn(length: usize, since_last_food: Duration) -> u32;
+
+pub fn flat_scoring(_length: usize, _since_last_food: Duration) -> u32 {
+    1
+}
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use rand_chacha::ChaCha12Rng;
+
+use crate::{Food, GridPosition, Player};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameSnapshot {
+    pub players: Vec<Player>,
+    pub foods: Vec<Food>,
+    pub obstacles: Vec<GridPosition>,
+    pub scores: Vec<u32>,
+    pub game_over: bool,
+    pub won: bool,
+    pub winner: Option<usize>,
+    pub frame_interval: Duration,
+    pub tick: u64,
+    pub elapsed: Duration,
+    pub hunger: Vec<Duration>,
+    pub last_meal: Vec<Duration>,
+    pub slow_motion: Duration,
+    pub shrinks: i16,
+    pub food_cursor: usize,
+    pub rng: ChaCha12Rng,
+}
@@ -0,0 +1,33 @@
+use ggez::audio::{self, SoundSource};
+use ggez::Context;
+
+use crate::GameEvent;
+
+const EAT_SOUND: &str = "/eat.wav";
+const DIE_SOUND: &str = "/die.wav";
+
+pub(crate) struct Sounds {
+    eat: Option<audio::Source>,
+    die: Option<audio::Source>,
+}
+
+impl Sounds {
+    pub(crate) fn load(context: &mut Context) -> Self {
+        Sounds {
+            eat: audio::Source::new(context, EAT_SOUND).ok(),
+            die: audio::Source::new(context, DIE_SOUND).ok(),
+        }
+    }
+
+    pub(crate) fn play(&mut self, context: &Context, event: &GameEvent) {
+        let source = match event {
+            GameEvent::AteFood { .. } => self.eat.as_mut(),
+            GameEvent::Died { .. } | GameEvent::TimeUp => self.die.as_mut(),
+            GameEvent::Restarted => None,
+        };
+
+        if let Some(source) = source {
+            let _ = source.play_detached(context);
+        }
+    }
+}
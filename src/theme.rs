@@ -0,0 +1,124 @@
+use ggez::graphics::Color;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BackgroundPattern {
+    Solid,
+    Checkerboard(Color),
+    Image(&'static str),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    pub background: Color,
+    pub grid_lines: Color,
+    pub head: Color,
+    pub body: Color,
+    pub tail: Option<Color>,
+    pub eye: Color,
+    pub rival_head: Color,
+    pub rival_body: Color,
+    pub rival_tail: Option<Color>,
+    pub food: Color,
+    pub bonus_food: Color,
+    pub wall_pass: Color,
+    pub slow_motion: Color,
+    pub obstacle: Color,
+    pub portal: Color,
+    pub warning: Color,
+    pub text: Color,
+    pub shape_cues: bool,
+    pub pattern: BackgroundPattern,
+    pub corner_radius: f32,
+}
+
+impl Theme {
+    pub fn classic() -> Self {
+        Theme {
+            background: Color::new(0.0, 1.0, 0.0, 1.0),
+            grid_lines: Color::new(0.0, 0.8, 0.0, 1.0),
+            head: Color::new(1.0, 0.0, 0.0, 1.0),
+            body: Color::new(1.0, 0.5, 0.0, 1.0),
+            tail: None,
+            eye: Color::new(0.0, 0.0, 0.0, 1.0),
+            rival_head: Color::new(0.5, 0.0, 0.5, 1.0),
+            rival_body: Color::new(0.8, 0.4, 0.8, 1.0),
+            rival_tail: None,
+            food: Color::new(0.0, 0.0, 1.0, 1.0),
+            bonus_food: Color::new(1.0, 0.84, 0.0, 1.0),
+            wall_pass: Color::new(0.0, 1.0, 1.0, 1.0),
+            slow_motion: Color::new(1.0, 1.0, 1.0, 1.0),
+            obstacle: Color::new(0.5, 0.5, 0.5, 1.0),
+            portal: Color::new(0.6, 0.0, 1.0, 1.0),
+            warning: Color::new(1.0, 1.0, 1.0, 0.6),
+            text: Color::new(0.0, 0.0, 0.0, 1.0),
+            shape_cues: false,
+            pattern: BackgroundPattern::Solid,
+            corner_radius: 0.0,
+        }
+    }
+
+    pub fn dark() -> Self {
+        Theme {
+            background: Color::new(0.08, 0.08, 0.1, 1.0),
+            grid_lines: Color::new(0.16, 0.16, 0.2, 1.0),
+            head: Color::new(0.3, 0.9, 0.4, 1.0),
+            body: Color::new(0.15, 0.6, 0.25, 1.0),
+            tail: None,
+            eye: Color::new(0.08, 0.08, 0.1, 1.0),
+            rival_head: Color::new(0.4, 0.6, 1.0, 1.0),
+            rival_body: Color::new(0.2, 0.35, 0.7, 1.0),
+            rival_tail: None,
+            food: Color::new(0.95, 0.3, 0.3, 1.0),
+            bonus_food: Color::new(1.0, 0.84, 0.0, 1.0),
+            wall_pass: Color::new(0.0, 1.0, 1.0, 1.0),
+            slow_motion: Color::new(1.0, 1.0, 1.0, 1.0),
+            obstacle: Color::new(0.4, 0.4, 0.45, 1.0),
+            portal: Color::new(0.7, 0.4, 1.0, 1.0),
+            warning: Color::new(1.0, 0.9, 0.3, 0.6),
+            text: Color::new(0.9, 0.9, 0.9, 1.0),
+            shape_cues: false,
+            pattern: BackgroundPattern::Solid,
+            corner_radius: 0.0,
+        }
+    }
+
+    pub fn colorblind() -> Self {
+        Theme {
+            background: Color::new(0.95, 0.95, 0.95, 1.0),
+            grid_lines: Color::new(0.85, 0.85, 0.85, 1.0),
+            head: Color::new(0.0, 0.45, 0.7, 1.0),
+            body: Color::new(0.34, 0.71, 0.91, 1.0),
+            tail: None,
+            eye: Color::new(1.0, 1.0, 1.0, 1.0),
+            rival_head: Color::new(0.84, 0.37, 0.0, 1.0),
+            rival_body: Color::new(0.9, 0.6, 0.0, 1.0),
+            rival_tail: None,
+            food: Color::new(0.0, 0.62, 0.45, 1.0),
+            bonus_food: Color::new(0.94, 0.89, 0.26, 1.0),
+            wall_pass: Color::new(0.0, 0.0, 0.0, 1.0),
+            slow_motion: Color::new(0.6, 0.6, 0.6, 1.0),
+            obstacle: Color::new(0.4, 0.4, 0.4, 1.0),
+            portal: Color::new(0.8, 0.47, 0.65, 1.0),
+            warning: Color::new(0.0, 0.0, 0.0, 0.4),
+            text: Color::new(0.0, 0.0, 0.0, 1.0),
+            shape_cues: true,
+            pattern: BackgroundPattern::Solid,
+            corner_radius: 0.0,
+        }
+    }
+}
+
+pub(crate) fn lerp(from: Color, to: Color, amount: f32) -> Color {
+    Color::new(
+        from.r + (to.r - from.r) * amount,
+        from.g + (to.g - from.g) * amount,
+        from.b + (to.b - from.b) * amount,
+        from.a + (to.a - from.a) * amount,
+    )
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::classic()
+    }
+}
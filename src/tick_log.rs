@@ -0,0 +1,73 @@
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{Collision, Direction, GridPosition};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickRecord {
+    pub tick: u64,
+    pub head: GridPosition,
+    pub direction: Direction,
+    pub food: Option<GridPosition>,
+    pub collision: Option<Collision>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickLog {
+    capacity: usize,
+    records: VecDeque<TickRecord>,
+}
+
+impl TickLog {
+    pub fn new(capacity: usize) -> Self {
+        TickLog {
+            capacity,
+            records: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn record(&mut self, record: TickRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.records.len() >= self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    pub fn records(&self) -> &VecDeque<TickRecord> {
+        &self.records
+    }
+
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = String::new();
+
+        for record in self.records.iter() {
+            let food = record
+                .food
+                .map_or_else(|| "-".to_string(), |food| format!("{},{}", food.x, food.y));
+            let collision = record
+                .collision
+                .map_or_else(|| "-".to_string(), |collision| format!("{:?}", collision));
+
+            let _ = writeln!(
+                contents,
+                "{} {},{} {:?} {} {}",
+                record.tick, record.head.x, record.head.y, record.direction, food, collision
+            );
+        }
+
+        fs::write(path, contents)
+    }
+}
@@ -0,0 +1,90 @@
+use snake_game::{
+    AiStrategy, Direction, Food, Game, GameConfig, GridPosition, ObstacleLayout, Player,
+    StepOutcome, WallMode,
+};
+
+mod common;
+
+fn cells(positions: &[(i16, i16)]) -> Vec<GridPosition> {
+    positions
+        .iter()
+        .map(|position| (*position).into())
+        .collect()
+}
+
+#[test]
+fn bfs_routes_around_a_wall_to_the_food() {
+    let wall = cells(&[(3, 1), (3, 2), (3, 3), (3, 4), (3, 5)]);
+    let mut game = Game::new(GameConfig {
+        grid: (7, 7),
+        wall_mode: WallMode::Solid,
+        obstacles: ObstacleLayout::Custom(wall),
+        spawn: Some(GridPosition::new(1, 3)),
+        food_sequence: Some(vec![GridPosition::new(5, 3)]),
+        cycle_food_sequence: false,
+        ai_mode: true,
+        ai_strategy: AiStrategy::Bfs,
+        ..common::quiet_config(17)
+    });
+
+    let mut outcome = StepOutcome::Moved;
+    while outcome == StepOutcome::Moved && game.tick() < 20 {
+        outcome = game.step(None);
+    }
+
+    assert_eq!(outcome, StepOutcome::Ate);
+    assert_eq!(game.players()[0].head.position, GridPosition::new(5, 3));
+    assert_eq!(
+        game.tick(),
+        10,
+        "took a longer route than the shortest path"
+    );
+}
+
+#[test]
+fn bfs_avoids_a_dead_end_when_the_food_is_unreachable() {
+    let grid = (7, 3);
+    let obstacles = cells(&[(2, 0), (4, 0), (4, 1), (5, 0), (6, 1)]);
+    let player = Player::spawn(GridPosition::new(3, 1), Direction::Right, 2, grid);
+    let foods = [Food::new(GridPosition::new(6, 0))];
+
+    let choice = AiStrategy::Bfs.choose(&player, &foods, &obstacles, grid, WallMode::Solid);
+
+    assert_eq!(choice, Some(Direction::Down));
+}
+
+#[test]
+fn greedy_takes_the_short_way_around_when_wrapping() {
+    let grid = (10, 10);
+    let player = Player::spawn(GridPosition::new(1, 5), Direction::Up, 2, grid);
+    let foods = [Food::new(GridPosition::new(9, 5))];
+
+    let choose = |wall_mode| AiStrategy::Greedy.choose(&player, &foods, &[], grid, wall_mode);
+
+    assert_eq!(choose(WallMode::Wrap), Some(Direction::Left));
+    assert_eq!(choose(WallMode::WrapHorizontal), Some(Direction::Left));
+    assert_eq!(choose(WallMode::Solid), Some(Direction::Right));
+    assert_eq!(choose(WallMode::WrapVertical), Some(Direction::Right));
+}
+
+#[test]
+fn greedy_wraps_vertically_toward_food_near_the_far_edge() {
+    let grid = (10, 10);
+    let player = Player::spawn(GridPosition::new(5, 1), Direction::Right, 2, grid);
+    let foods = [Food::new(GridPosition::new(5, 8))];
+
+    let choose = |wall_mode| AiStrategy::Greedy.choose(&player, &foods, &[], grid, wall_mode);
+
+    assert_eq!(choose(WallMode::Wrap), Some(Direction::Up));
+    assert_eq!(choose(WallMode::Solid), Some(Direction::Down));
+}
+
+#[test]
+fn toroidal_distance_takes_the_shorter_way_on_each_axis() {
+    let grid = (10, 10);
+    let head = GridPosition::new(1, 5);
+
+    assert_eq!(head.toroidal_distance(GridPosition::new(9, 5), grid), 2);
+    assert_eq!(head.toroidal_distance(GridPosition::new(4, 5), grid), 3);
+    assert_eq!(head.toroidal_distance(GridPosition::new(9, 9), grid), 6);
+}
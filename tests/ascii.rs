@@ -0,0 +1,50 @@
+use snake_game::{Game, GameConfig, GridPosition, ObstacleLayout, WallMode};
+
+mod common;
+
+#[test]
+fn board_renders_as_ascii() {
+    let game = Game::new(GameConfig {
+        grid: (8, 5),
+        wall_mode: WallMode::Solid,
+        obstacles: ObstacleLayout::Custom(vec![GridPosition::new(6, 0), GridPosition::new(6, 1)]),
+        two_player: true,
+        initial_length: 2,
+        food_count: 2,
+        food_sequence: Some(vec![GridPosition::new(0, 0), GridPosition::new(3, 4)]),
+        ..common::quiet_config(2)
+    });
+
+    assert_eq!(
+        game.to_ascii(),
+        concat!(
+            "*.....#.\n",
+            "......#.\n",
+            ".oO..Oo.\n",
+            "........\n",
+            "...*....\n",
+        )
+    );
+}
+
+#[test]
+fn a_head_on_a_fresh_dropping_still_renders_as_a_head() {
+    let mut game = Game::new(GameConfig {
+        grid: (6, 3),
+        wall_mode: WallMode::Solid,
+        droppings: true,
+        initial_length: 2,
+        spawn: Some(GridPosition::new(1, 1)),
+        food_sequence: Some(vec![GridPosition::new(2, 1), GridPosition::new(5, 0)]),
+        cycle_food_sequence: false,
+        ..common::quiet_config(3)
+    });
+
+    game.step(None);
+    assert!(game.obstacles().contains(&GridPosition::new(2, 1)));
+
+    assert_eq!(
+        game.to_ascii(),
+        concat!(".....*\n", "ooO...\n", "......\n",)
+    );
+}
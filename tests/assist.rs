@@ -0,0 +1,110 @@
+use snake_game::{Direction, Game, GameConfig, GameMode, GridPosition, StepOutcome, WallMode};
+
+mod common;
+
+fn assisted(spawn: (i16, i16), food_sequence: Vec<GridPosition>) -> GameConfig {
+    GameConfig {
+        grid: (10, 10),
+        wall_mode: WallMode::Solid,
+        assist: true,
+        spawn: Some(spawn.into()),
+        food_sequence: Some(food_sequence),
+        cycle_food_sequence: false,
+        ..common::quiet_config(5)
+    }
+}
+
+fn head(game: &Game) -> GridPosition {
+    game.players()[0].head.position
+}
+
+#[test]
+fn suicidal_input_does_not_end_the_game() {
+    let mut game = Game::new(assisted((5, 0), vec![GridPosition::new(9, 9)]));
+
+    game.turn_player(0, Direction::Up);
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert!(!game.is_finished());
+    assert_eq!(head(&game), GridPosition::new(6, 0));
+}
+
+#[test]
+fn chasing_the_tail_is_allowed() {
+    let mut game = Game::new(GameConfig {
+        initial_length: 4,
+        ..assisted((5, 5), vec![GridPosition::new(9, 9)])
+    });
+
+    game.turn_player(0, Direction::Up);
+    game.step(None);
+    game.turn_player(0, Direction::Left);
+    game.step(None);
+    game.turn_player(0, Direction::Down);
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(head(&game), GridPosition::new(4, 5));
+}
+
+#[test]
+fn a_growing_tail_is_not_safe() {
+    let mut game = Game::new(GameConfig {
+        growth_per_food: 5,
+        ..assisted(
+            (5, 5),
+            vec![GridPosition::new(6, 5), GridPosition::new(9, 9)],
+        )
+    });
+
+    assert_eq!(game.step(None), StepOutcome::Ate);
+    game.turn_player(0, Direction::Up);
+    game.step(None);
+    game.turn_player(0, Direction::Left);
+    game.step(None);
+    game.step(None);
+    assert_eq!(
+        game.players()[0]
+            .body()
+            .back()
+            .map(|segment| segment.position),
+        Some(GridPosition::new(4, 5))
+    );
+    game.turn_player(0, Direction::Down);
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(head(&game), GridPosition::new(3, 4));
+}
+
+#[test]
+fn turns_that_wrap_in_practice_are_kept() {
+    let mut game = Game::new(GameConfig {
+        mode: GameMode::Practice,
+        ..assisted((5, 0), vec![GridPosition::new(9, 9)])
+    });
+
+    game.turn_player(0, Direction::Up);
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(head(&game), GridPosition::new(5, 9));
+}
+
+#[test]
+fn turns_into_an_opponent_are_discarded() {
+    let mut game = Game::new(GameConfig {
+        grid: (12, 7),
+        two_player: true,
+        initial_length: 4,
+        spawn: None,
+        ..assisted((0, 0), vec![GridPosition::new(0, 6)])
+    });
+
+    game.turn_player(0, Direction::Up);
+    game.step(None);
+    game.turn_player(0, Direction::Right);
+    game.step(None);
+    game.step(None);
+    game.turn_player(0, Direction::Down);
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(game.players()[0].collision, None);
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use snake_game::{
+    AiStrategy, Collision, Direction, Game, GameConfig, GameMode, GridPosition, Player, StepOutcome,
+};
+
+mod common;
+
+fn body(player: &Player) -> Vec<(i16, i16)> {
+    player
+        .body()
+        .iter()
+        .map(|segment| (segment.position.x, segment.position.y))
+        .collect()
+}
+
+fn cell_counts(player: &Player) -> HashMap<GridPosition, usize> {
+    let mut counts = HashMap::new();
+    for segment in player.body() {
+        *counts.entry(segment.position).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+#[test]
+fn the_body_follows_the_head_segment_by_segment() {
+    let mut game = Game::new(GameConfig {
+        spawn: Some(GridPosition::new(5, 5)),
+        initial_length: 4,
+        food_sequence: Some(vec![GridPosition::new(20, 20)]),
+        cycle_food_sequence: false,
+        ..common::quiet_config(14)
+    });
+
+    game.step(None);
+    game.turn(Direction::Up);
+    game.step(None);
+    game.turn(Direction::Left);
+    game.step(None);
+    game.step(None);
+
+    let player = &game.players()[0];
+    assert_eq!(game.score(), 0);
+    assert_eq!(player.head.position, GridPosition::new(4, 4));
+    assert_eq!(body(player), vec![(5, 4), (6, 4), (6, 5)]);
+
+    game.turn(Direction::Up);
+    game.step(None);
+
+    assert_eq!(body(&game.players()[0]), vec![(4, 4), (5, 4), (6, 4)]);
+}
+
+#[test]
+fn the_occupied_set_tracks_the_body_over_many_moves() {
+    let mut game = Game::new(GameConfig {
+        grid: (12, 12),
+        ai_mode: true,
+        ai_strategy: AiStrategy::Bfs,
+        ..common::quiet_config(15)
+    });
+
+    for _ in 0..500 {
+        if game.is_finished() {
+            break;
+        }
+        game.step(None);
+
+        let player = &game.players()[0];
+        assert_eq!(
+            player.occupied(),
+            &cell_counts(player),
+            "tick {}",
+            game.tick()
+        );
+    }
+
+    assert!(game.players()[0].length() > 5);
+}
+
+#[test]
+fn biting_the_body_is_still_detected() {
+    let mut game = Game::new(GameConfig {
+        spawn: Some(GridPosition::new(5, 5)),
+        initial_length: 5,
+        food_sequence: Some(vec![GridPosition::new(20, 20)]),
+        ..common::quiet_config(16)
+    });
+
+    game.turn(Direction::Up);
+    game.step(None);
+    game.turn(Direction::Left);
+    game.step(None);
+    game.turn(Direction::Down);
+
+    assert_eq!(game.step(None), StepOutcome::Died(Collision::Itself));
+}
+
+#[test]
+fn overlapping_segments_keep_their_cells_occupied() {
+    let mut game = Game::new(GameConfig {
+        mode: GameMode::Practice,
+        spawn: Some(GridPosition::new(8, 5)),
+        initial_length: 7,
+        food_sequence: Some(vec![GridPosition::new(20, 20)]),
+        ..common::quiet_config(25)
+    });
+    let turns = [
+        Some(Direction::Up),
+        Some(Direction::Left),
+        Some(Direction::Down),
+        None,
+        None,
+        Some(Direction::Right),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    let mut overlapped = false;
+    for turn in turns {
+        if let Some(turn) = turn {
+            game.turn(turn);
+        }
+        game.step(None);
+
+        let player = &game.players()[0];
+        let counts = cell_counts(player);
+        overlapped |= counts.values().any(|count| *count > 1);
+
+        assert_eq!(player.occupied(), &counts, "tick {}", game.tick());
+        for cell in counts.keys() {
+            assert!(player.occupies(*cell), "{:?} at tick {}", cell, game.tick());
+        }
+    }
+
+    assert!(overlapped, "the snake never crossed itself");
+}
@@ -0,0 +1,107 @@
+use snake_game::{Collision, Direction, Game, GameConfig, GridPosition, StepOutcome};
+
+mod common;
+
+fn looping_snake() -> Game {
+    Game::new(GameConfig {
+        spawn: Some(GridPosition::new(5, 5)),
+        initial_length: 4,
+        food_sequence: Some(vec![GridPosition::new(20, 20)]),
+        ..common::quiet_config(8)
+    })
+}
+
+#[test]
+fn entering_the_cell_the_tail_leaves_is_not_a_collision() {
+    let mut game = looping_snake();
+
+    for turn in [
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+    ]
+    .repeat(3)
+    {
+        let tail = game.players()[0]
+            .body()
+            .back()
+            .map(|segment| segment.position);
+
+        game.turn(turn);
+        assert_eq!(game.step(None), StepOutcome::Moved, "turning {:?}", turn);
+        assert_eq!(game.players()[0].collision, None);
+        assert_eq!(game.players()[0].length(), 4);
+
+        if game.tick() > 2 {
+            assert_eq!(Some(game.players()[0].head.position), tail);
+        }
+    }
+}
+
+fn biting_snake(self_collision_grace: u64) -> Game {
+    let mut game = Game::new(GameConfig {
+        spawn: Some(GridPosition::new(5, 5)),
+        initial_length: 5,
+        self_collision_grace,
+        food_sequence: Some(vec![GridPosition::new(20, 20)]),
+        ..common::quiet_config(23)
+    });
+
+    game.turn(Direction::Up);
+    game.step(None);
+    game.turn(Direction::Left);
+    game.step(None);
+    game.turn(Direction::Down);
+
+    game
+}
+
+#[test]
+fn an_early_overlap_is_forgiven_during_the_grace_period() {
+    let mut game = biting_snake(5);
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert!(!game.is_finished());
+    assert_eq!(game.players()[0].collision, None);
+}
+
+#[test]
+fn without_grace_an_early_overlap_is_fatal() {
+    let mut game = biting_snake(0);
+
+    assert_eq!(game.step(None), StepOutcome::Died(Collision::Itself));
+}
+
+#[test]
+fn a_cell_crossed_during_grace_still_bites_after_it_ends() {
+    let mut game = Game::new(GameConfig {
+        spawn: Some(GridPosition::new(8, 5)),
+        initial_length: 7,
+        self_collision_grace: 3,
+        food_sequence: Some(vec![GridPosition::new(20, 20)]),
+        ..common::quiet_config(26)
+    });
+
+    // The third move crosses the body at (7, 5) while grace still applies.
+    let turns = [
+        Some(Direction::Up),
+        Some(Direction::Left),
+        Some(Direction::Down),
+        None,
+        Some(Direction::Left),
+        Some(Direction::Up),
+    ];
+    for turn in turns {
+        if let Some(turn) = turn {
+            game.turn(turn);
+        }
+        assert_eq!(game.step(None), StepOutcome::Moved, "tick {}", game.tick());
+    }
+    assert_eq!(game.players()[0].grace, 0);
+    assert!(game.players()[0].occupies(GridPosition::new(7, 5)));
+
+    game.turn(Direction::Right);
+
+    assert_eq!(game.step(None), StepOutcome::Died(Collision::Itself));
+}
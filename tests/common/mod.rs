@@ -0,0 +1,11 @@
+use snake_game::GameConfig;
+
+pub fn quiet_config(seed: u64) -> GameConfig {
+    GameConfig {
+        high_score_file: None,
+        seed: Some(seed),
+        bonus_food_chance: 0.0,
+        power_up_chance: 0.0,
+        ..GameConfig::default()
+    }
+}
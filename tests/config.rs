@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use snake_game::{run_headless, AiStrategy, Game, GameConfig, GridPosition, Replay};
+
+mod common;
+
+#[test]
+fn the_default_config_is_valid() {
+    assert_eq!(GameConfig::default().validate(), Ok(()));
+}
+
+#[test]
+fn frame_rates_that_cannot_tick_are_rejected() {
+    for frames_per_second in [0.0, -8.0, f32::NAN, f32::INFINITY] {
+        let config = GameConfig {
+            frames_per_second,
+            ..GameConfig::default()
+        };
+
+        assert!(
+            config.validate().is_err(),
+            "{} fps was accepted",
+            frames_per_second
+        );
+    }
+}
+
+#[test]
+fn games_are_not_built_from_a_config_that_cannot_tick() {
+    for frames_per_second in [0.0, f32::NAN] {
+        let config = GameConfig {
+            frames_per_second,
+            ..common::quiet_config(1)
+        };
+
+        assert!(Game::try_new(config.clone()).is_err());
+        assert!(run_headless(config.clone(), 10, AiStrategy::Bfs).is_err());
+        assert!(Replay::new(1, &config)
+            .play(common::quiet_config(1))
+            .is_err());
+    }
+}
+
+#[test]
+fn frame_intervals_are_not_truncated_to_whole_milliseconds() {
+    let config = GameConfig {
+        frames_per_second: 7.0,
+        ..GameConfig::default()
+    };
+
+    let interval = config.frame_interval();
+    assert!(interval > Duration::from_millis(142));
+    assert!((interval.as_secs_f64() - 1.0 / 7.0).abs() < 1e-6);
+}
+
+#[test]
+fn seven_ticks_at_seven_fps_take_one_second() {
+    let mut game = Game::new(GameConfig {
+        frames_per_second: 7.0,
+        speed_up_per_food: Duration::ZERO,
+        food_sequence: Some(vec![GridPosition::new(0, 0)]),
+        ..common::quiet_config(10)
+    });
+
+    for _ in 0..7 {
+        game.step(None);
+    }
+
+    let drift = game.elapsed().as_secs_f64() - 1.0;
+    assert!(drift.abs() < 1e-3, "drifted {}s", drift);
+}
@@ -0,0 +1,26 @@
+use snake_game::Direction;
+
+#[test]
+fn turning_left_rotates_counterclockwise() {
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::Left.turn_left(), Direction::Down);
+    assert_eq!(Direction::Down.turn_left(), Direction::Right);
+    assert_eq!(Direction::Right.turn_left(), Direction::Up);
+}
+
+#[test]
+fn turning_right_rotates_clockwise() {
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Right.turn_right(), Direction::Down);
+    assert_eq!(Direction::Down.turn_right(), Direction::Left);
+    assert_eq!(Direction::Left.turn_right(), Direction::Up);
+}
+
+#[test]
+fn rotations_compose_like_quarter_turns() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_left().turn_left(), direction.inverse());
+        assert_eq!(direction.turn_right().turn_right(), direction.inverse());
+    }
+}
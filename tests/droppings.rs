@@ -0,0 +1,33 @@
+use snake_game::{Collision, Direction, Game, GameConfig, GridPosition, StepOutcome, WallMode};
+
+mod common;
+
+#[test]
+fn eating_leaves_a_block_that_kills_later() {
+    let mut game = Game::new(GameConfig {
+        grid: (20, 10),
+        wall_mode: WallMode::Solid,
+        droppings: true,
+        food_sequence: Some(vec![GridPosition::new(8, 5), GridPosition::new(0, 0)]),
+        cycle_food_sequence: false,
+        ..common::quiet_config(4)
+    });
+
+    for _ in 0..3 {
+        game.step(None);
+    }
+    assert!(game.obstacles().contains(&GridPosition::new(8, 5)));
+    assert!(game
+        .foods()
+        .iter()
+        .all(|food| food.position != GridPosition::new(8, 5)));
+
+    for direction in [Direction::Down, Direction::Right, Direction::Up] {
+        assert_eq!(game.step(Some(direction)), StepOutcome::Moved);
+    }
+
+    assert_eq!(
+        game.step(Some(Direction::Left)),
+        StepOutcome::Died(Collision::Obstacle)
+    );
+}
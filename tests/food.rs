@@ -0,0 +1,75 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use snake_game::{
+    AiStrategy, Direction, Food, Game, GameConfig, GridPosition, Player, StepOutcome,
+};
+
+mod common;
+
+#[test]
+fn respawned_food_avoids_a_snake_filling_most_of_the_grid() {
+    let grid = (8, 1);
+    let player = Player::spawn(GridPosition::new(7, 0), Direction::Right, 7, grid);
+    assert_eq!(player.length(), 7);
+
+    for seed in 0..100 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut food = Food::new(GridPosition::new(7, 0));
+
+        food.respawn_avoiding(&mut rng, &player, grid);
+
+        assert_eq!(food.position, GridPosition::new(0, 0), "seed {}", seed);
+    }
+}
+
+#[test]
+fn respawned_food_stays_put_when_no_cell_is_free() {
+    let grid = (4, 1);
+    let player = Player::spawn(GridPosition::new(3, 0), Direction::Right, 4, grid);
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut food = Food::new(GridPosition::new(2, 0));
+
+    food.respawn_avoiding(&mut rng, &player, grid);
+
+    assert_eq!(food.position, GridPosition::new(2, 0));
+}
+
+fn food_positions(seed: u64) -> Vec<GridPosition> {
+    let mut game = Game::new(GameConfig {
+        grid: (10, 10),
+        ai_mode: true,
+        ai_strategy: AiStrategy::Bfs,
+        ..common::quiet_config(seed)
+    });
+    let mut positions = vec![game.foods()[0].position];
+
+    for _ in 0..300 {
+        if game.is_finished() {
+            break;
+        }
+        if game.step(None) == StepOutcome::Ate {
+            positions.extend(game.foods().iter().map(|food| food.position));
+        }
+    }
+
+    positions
+}
+
+#[test]
+fn a_fixed_seed_places_the_same_foods() {
+    let positions = food_positions(42);
+
+    assert!(
+        positions.len() > 5,
+        "the bot only ate {} foods",
+        positions.len() - 1
+    );
+    assert_eq!(food_positions(42), positions);
+}
+
+#[test]
+fn different_seeds_place_different_foods() {
+    let first = food_positions(1);
+
+    assert!((2..10).any(|seed| food_positions(seed) != first));
+}
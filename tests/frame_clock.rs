@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use snake_game::{FrameClock, GameConfig};
+
+fn steps_over(total: Duration, frame: Duration, interval: Duration) -> u32 {
+    let mut clock = FrameClock::new();
+    let mut steps = 0;
+    let mut elapsed = Duration::ZERO;
+
+    while elapsed < total {
+        steps += clock.advance(frame, interval, || Some(interval));
+        elapsed += frame;
+    }
+
+    steps
+}
+
+#[test]
+fn logic_rate_is_independent_of_the_render_rate() {
+    let interval = GameConfig::default().frame_interval();
+    let total = Duration::from_secs(10);
+    let expected = (total.as_secs_f64() / interval.as_secs_f64()) as u32;
+
+    for render_fps in [30.0, 60.0, 144.0, 240.0, 1000.0] {
+        let frame = Duration::from_secs_f64(1.0 / render_fps);
+        let steps = steps_over(total, frame, interval);
+
+        assert!(
+            steps.abs_diff(expected) <= 1,
+            "{} steps at {} fps, expected {}",
+            steps,
+            render_fps,
+            expected
+        );
+    }
+}
+
+#[test]
+fn effective_rate_matches_fractional_frame_rates() {
+    for fps in [7.0, 9.5, 13.0] {
+        let interval = GameConfig {
+            frames_per_second: fps,
+            ..GameConfig::default()
+        }
+        .frame_interval();
+        let steps = steps_over(Duration::from_secs(60), Duration::from_millis(16), interval);
+        let rate = steps as f32 / 60.0;
+
+        assert!(
+            (rate - fps).abs() < 0.05,
+            "{} ticks/s for {} fps",
+            rate,
+            fps
+        );
+    }
+}
+
+#[test]
+fn long_frames_catch_up_a_bounded_number_of_steps() {
+    let interval = Duration::from_millis(100);
+    let mut clock = FrameClock::new();
+
+    assert_eq!(
+        clock.advance(Duration::from_millis(250), interval, || Some(interval)),
+        2
+    );
+    assert_eq!(clock.accumulated(), Duration::from_millis(50));
+
+    assert_eq!(
+        clock.advance(Duration::from_secs(3), interval, || Some(interval)),
+        5
+    );
+    assert_eq!(clock.accumulated(), Duration::ZERO);
+}
+
+#[test]
+fn stopping_early_keeps_the_remaining_time() {
+    let interval = Duration::from_millis(100);
+    let mut clock = FrameClock::new();
+
+    assert_eq!(
+        clock.advance(Duration::from_millis(350), interval, || None),
+        1
+    );
+    assert_eq!(clock.accumulated(), Duration::from_millis(250));
+}
+
+#[test]
+fn a_changed_interval_applies_to_the_next_step() {
+    let mut clock = FrameClock::new();
+
+    let steps = clock.advance(
+        Duration::from_millis(300),
+        Duration::from_millis(100),
+        || Some(Duration::from_millis(50)),
+    );
+
+    assert_eq!(steps, 5);
+    assert_eq!(clock.accumulated(), Duration::from_millis(0));
+}
@@ -0,0 +1,61 @@
+use snake_game::{Game, GameConfig, GridPosition, Player, WallMode};
+
+mod common;
+
+fn growing_game(growth_per_food: usize) -> Game {
+    Game::new(GameConfig {
+        grid: (20, 10),
+        wall_mode: WallMode::Solid,
+        growth_per_food,
+        food_sequence: Some(vec![GridPosition::new(8, 5), GridPosition::new(0, 0)]),
+        cycle_food_sequence: false,
+        ..common::quiet_config(11)
+    })
+}
+
+fn lengths(game: &mut Game, ticks: usize) -> Vec<usize> {
+    (0..ticks)
+        .map(|_| {
+            game.step(None);
+            game.players()[0].length()
+        })
+        .collect()
+}
+
+#[test]
+fn single_growth_adds_one_segment() {
+    let mut game = growing_game(1);
+
+    assert_eq!(lengths(&mut game, 6), vec![2, 2, 3, 3, 3, 3]);
+    assert_eq!(game.score(), 1);
+}
+
+#[test]
+fn triple_growth_spreads_over_the_next_ticks() {
+    let mut game = growing_game(3);
+
+    assert_eq!(lengths(&mut game, 7), vec![2, 2, 3, 4, 5, 5, 5]);
+    assert_eq!(game.players()[0].pending_growth, 0);
+    assert_eq!(game.score(), 3);
+}
+
+#[test]
+fn queued_growth_is_spent_one_segment_per_tick() {
+    let mut player = Player::new(GridPosition::new(2, 2));
+    let config = GameConfig {
+        grid: (10, 10),
+        ..GameConfig::default()
+    };
+
+    player.grow(2);
+
+    let lengths: Vec<usize> = (0..4)
+        .map(|_| {
+            player.update(&[], &[], &config, config.wall_mode);
+            player.length()
+        })
+        .collect();
+
+    assert_eq!(lengths, vec![3, 4, 4, 4]);
+    assert_eq!(player.pending_growth, 0);
+}
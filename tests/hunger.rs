@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use snake_game::{Collision, Game, GameConfig, GridPosition, StepOutcome, WallMode};
+
+mod common;
+
+#[test]
+fn hunger_stops_at_the_minimum_length_and_ends_the_game() {
+    let mut game = Game::new(GameConfig {
+        grid: (30, 5),
+        frames_per_second: 10.0,
+        wall_mode: WallMode::Solid,
+        initial_length: 5,
+        min_length: 3,
+        hunger_interval: Some(Duration::from_millis(100)),
+        food_sequence: Some(vec![GridPosition::new(0, 0)]),
+        ..common::quiet_config(9)
+    });
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(game.players()[0].length(), 4);
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(game.players()[0].length(), 3);
+
+    assert_eq!(game.step(None), StepOutcome::Died(Collision::Starved));
+    assert_eq!(game.players()[0].length(), 3);
+    assert!(game.is_game_over());
+}
@@ -0,0 +1,94 @@
+use ggez::event::KeyCode;
+use snake_game::{steer, ControlScheme, Direction, Game, GameConfig, KeyMap};
+
+mod common;
+
+fn relative_game(key_repeat: bool) -> Game {
+    Game::new(GameConfig {
+        control_scheme: ControlScheme::Relative,
+        key_repeat,
+        ..common::quiet_config(2)
+    })
+}
+
+fn hold_left(game: &mut Game) {
+    let key_maps = [KeyMap::default()];
+
+    steer(game, &key_maps, KeyCode::Left, false);
+    for _ in 0..3 {
+        steer(game, &key_maps, KeyCode::Left, true);
+    }
+}
+
+#[test]
+fn a_held_key_turns_once_when_repeats_are_ignored() {
+    let mut game = relative_game(false);
+
+    hold_left(&mut game);
+
+    assert_eq!(
+        game.players()[0].queued_turns,
+        vec![Direction::Up],
+        "repeats queued extra turns"
+    );
+}
+
+#[test]
+fn a_held_key_keeps_turning_when_repeats_are_enabled() {
+    let mut game = relative_game(true);
+
+    hold_left(&mut game);
+
+    assert_eq!(
+        game.players()[0].queued_turns,
+        vec![Direction::Up, Direction::Left]
+    );
+}
+
+#[test]
+fn keys_steer_the_player_their_map_belongs_to() {
+    let mut game = Game::new(GameConfig {
+        two_player: true,
+        ..common::quiet_config(2)
+    });
+    let key_maps = [KeyMap::wasd(), KeyMap::arrows()];
+
+    steer(&mut game, &key_maps, KeyCode::W, false);
+    steer(&mut game, &key_maps, KeyCode::Down, false);
+
+    assert_eq!(game.players()[0].queued_turns, vec![Direction::Up]);
+    assert_eq!(game.players()[1].queued_turns, vec![Direction::Down]);
+}
+
+#[test]
+fn relative_controls_turn_from_the_current_heading() {
+    let mut game = relative_game(false);
+    let key_maps = [KeyMap::arrows()];
+    let presses = [
+        KeyCode::Left,
+        KeyCode::Left,
+        KeyCode::Up,
+        KeyCode::Right,
+        KeyCode::Down,
+    ];
+
+    let headings: Vec<Direction> = presses
+        .iter()
+        .map(|key| {
+            steer(&mut game, &key_maps, *key, false);
+            game.step(None);
+            game.players()[0].direction
+        })
+        .collect();
+
+    assert_eq!(
+        headings,
+        vec![
+            Direction::Up,
+            Direction::Left,
+            Direction::Left,
+            Direction::Up,
+            Direction::Up
+        ]
+    );
+}
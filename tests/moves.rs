@@ -0,0 +1,221 @@
+use std::collections::{BTreeSet, HashSet};
+
+use snake_game::{Direction, GridPosition, WallMode};
+
+const GRID: (i16, i16) = (10, 8);
+
+#[test]
+fn wrap_mode_moves_across_every_edge() {
+    let corner = GridPosition::new(0, 0);
+    let far_corner = GridPosition::new(9, 7);
+
+    assert_eq!(
+        corner.moved(Direction::Left, GRID, WallMode::Wrap),
+        Some(GridPosition::new(9, 0))
+    );
+    assert_eq!(
+        corner.moved(Direction::Up, GRID, WallMode::Wrap),
+        Some(GridPosition::new(0, 7))
+    );
+    assert_eq!(
+        far_corner.moved(Direction::Right, GRID, WallMode::Wrap),
+        Some(GridPosition::new(0, 7))
+    );
+    assert_eq!(
+        far_corner.moved(Direction::Down, GRID, WallMode::Wrap),
+        Some(GridPosition::new(9, 0))
+    );
+}
+
+#[test]
+fn solid_walls_block_moves_off_the_grid() {
+    let corner = GridPosition::new(0, 0);
+
+    assert_eq!(corner.moved(Direction::Left, GRID, WallMode::Solid), None);
+    assert_eq!(corner.moved(Direction::Up, GRID, WallMode::Solid), None);
+    assert_eq!(
+        corner.moved(Direction::Right, GRID, WallMode::Solid),
+        Some(GridPosition::new(1, 0))
+    );
+    assert_eq!(
+        corner.moved(Direction::Down, GRID, WallMode::Solid),
+        Some(GridPosition::new(0, 1))
+    );
+}
+
+#[test]
+fn single_axis_wrapping_blocks_the_other_axis() {
+    let corner = GridPosition::new(0, 0);
+
+    assert_eq!(
+        corner.moved(Direction::Left, GRID, WallMode::WrapHorizontal),
+        Some(GridPosition::new(9, 0))
+    );
+    assert_eq!(
+        corner.moved(Direction::Up, GRID, WallMode::WrapHorizontal),
+        None
+    );
+    assert_eq!(
+        corner.moved(Direction::Left, GRID, WallMode::WrapVertical),
+        None
+    );
+}
+
+#[test]
+fn single_axis_wrapping_at_every_edge() {
+    let corner = GridPosition::new(0, 0);
+    let far_corner = GridPosition::new(9, 7);
+
+    let cases = [
+        (
+            corner,
+            Direction::Left,
+            WallMode::WrapHorizontal,
+            WallMode::WrapVertical,
+            GridPosition::new(9, 0),
+        ),
+        (
+            far_corner,
+            Direction::Right,
+            WallMode::WrapHorizontal,
+            WallMode::WrapVertical,
+            GridPosition::new(0, 7),
+        ),
+        (
+            corner,
+            Direction::Up,
+            WallMode::WrapVertical,
+            WallMode::WrapHorizontal,
+            GridPosition::new(0, 7),
+        ),
+        (
+            far_corner,
+            Direction::Down,
+            WallMode::WrapVertical,
+            WallMode::WrapHorizontal,
+            GridPosition::new(9, 0),
+        ),
+    ];
+
+    for (position, direction, wraps, blocks, wrapped) in cases {
+        assert_eq!(
+            position.moved(direction, GRID, wraps),
+            Some(wrapped),
+            "{:?} from {:?} in {:?}",
+            direction,
+            position,
+            wraps
+        );
+        assert_eq!(
+            position.moved(direction, GRID, blocks),
+            None,
+            "{:?} from {:?} in {:?}",
+            direction,
+            position,
+            blocks
+        );
+    }
+}
+
+#[test]
+fn single_axis_wrapping_moves_freely_along_the_blocked_axis() {
+    let middle = GridPosition::new(4, 3);
+
+    for wall_mode in [WallMode::WrapHorizontal, WallMode::WrapVertical] {
+        assert_eq!(
+            middle.moved(Direction::Right, GRID, wall_mode),
+            Some(GridPosition::new(5, 3))
+        );
+        assert_eq!(
+            middle.moved(Direction::Down, GRID, wall_mode),
+            Some(GridPosition::new(4, 4))
+        );
+    }
+}
+
+#[test]
+fn neighbors_wrap_around_the_edges() {
+    assert_eq!(
+        GridPosition::new(4, 3).neighbors(GRID),
+        [
+            GridPosition::new(4, 2),
+            GridPosition::new(4, 4),
+            GridPosition::new(3, 3),
+            GridPosition::new(5, 3),
+        ]
+    );
+    assert_eq!(
+        GridPosition::new(0, 0).neighbors(GRID),
+        [
+            GridPosition::new(0, 7),
+            GridPosition::new(0, 1),
+            GridPosition::new(9, 0),
+            GridPosition::new(1, 0),
+        ]
+    );
+    assert_eq!(
+        GridPosition::new(9, 7).neighbors(GRID),
+        [
+            GridPosition::new(9, 6),
+            GridPosition::new(9, 0),
+            GridPosition::new(8, 7),
+            GridPosition::new(0, 7),
+        ]
+    );
+}
+
+#[test]
+fn manhattan_distance_counts_steps_on_both_axes() {
+    let origin = GridPosition::new(2, 3);
+
+    assert_eq!(origin.manhattan_distance(origin), 0);
+    assert_eq!(origin.manhattan_distance(GridPosition::new(7, 1)), 7);
+    assert_eq!(GridPosition::new(7, 1).manhattan_distance(origin), 7);
+}
+
+#[test]
+fn positions_round_trip_through_a_hash_set() {
+    let positions = [
+        GridPosition::new(1, 2),
+        GridPosition::new(3, 4),
+        GridPosition::new(1, 2),
+    ];
+    let set: HashSet<GridPosition> = positions.into_iter().collect();
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&GridPosition::new(1, 2)));
+    assert!(set.contains(&GridPosition::new(3, 4)));
+    assert!(!set.contains(&GridPosition::new(2, 1)));
+}
+
+#[test]
+fn positions_sort_by_row_then_column() {
+    let mut positions = vec![
+        GridPosition::new(5, 1),
+        GridPosition::new(0, 2),
+        GridPosition::new(2, 1),
+        GridPosition::new(9, 0),
+    ];
+    positions.sort();
+
+    assert_eq!(
+        positions,
+        vec![
+            GridPosition::new(9, 0),
+            GridPosition::new(2, 1),
+            GridPosition::new(5, 1),
+            GridPosition::new(0, 2),
+        ]
+    );
+
+    let set: BTreeSet<GridPosition> = positions.iter().copied().collect();
+    assert_eq!(set.first(), Some(&GridPosition::new(9, 0)));
+}
+
+#[test]
+fn adding_an_offset_shifts_the_position() {
+    let position = GridPosition::new(4, 3);
+
+    assert_eq!(position + (0, -1), GridPosition::new(4, 2));
+    assert_eq!(position + (2, 5), GridPosition::new(6, 8));
+}
@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use snake_game::{
+    Collision, Direction, Game, GameConfig, GameMode, GridPosition, StepOutcome, WallMode,
+};
+
+mod common;
+
+fn corridor(food_sequence: Vec<GridPosition>) -> Game {
+    Game::new(GameConfig {
+        grid: (4, 1),
+        wall_mode: WallMode::Solid,
+        spawn: Some(GridPosition::new(1, 0)),
+        food_sequence: Some(food_sequence),
+        cycle_food_sequence: false,
+        ..common::quiet_config(12)
+    })
+}
+
+#[test]
+fn filling_the_grid_wins() {
+    let mut game = corridor(vec![GridPosition::new(2, 0), GridPosition::new(3, 0)]);
+
+    assert_eq!(game.step(None), StepOutcome::Ate);
+    assert!(!game.is_won());
+    assert_eq!(game.step(None), StepOutcome::Won);
+
+    assert!(game.is_won());
+    assert!(!game.is_game_over());
+    assert_eq!(game.players()[0].length(), 4);
+    assert!(game.foods().is_empty());
+    assert_eq!(game.step(None), StepOutcome::Skipped);
+}
+
+#[test]
+fn each_step_reports_what_happened() {
+    let mut game = corridor(vec![GridPosition::new(3, 0)]);
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(game.step(None), StepOutcome::Ate);
+    assert_eq!(game.step(None), StepOutcome::Died(Collision::Wall));
+    assert_eq!(game.step(None), StepOutcome::Skipped);
+}
+
+#[test]
+fn biting_itself_reports_the_collision() {
+    let mut game = Game::new(GameConfig {
+        spawn: Some(GridPosition::new(5, 5)),
+        initial_length: 5,
+        food_sequence: Some(vec![GridPosition::new(20, 20)]),
+        ..common::quiet_config(12)
+    });
+
+    game.turn(Direction::Up);
+    game.step(None);
+    game.turn(Direction::Left);
+    game.step(None);
+    game.turn(Direction::Down);
+
+    assert_eq!(game.step(None), StepOutcome::Died(Collision::Itself));
+}
+
+#[test]
+fn running_out_of_time_reports_time_up() {
+    let mut game = Game::new(GameConfig {
+        mode: GameMode::TimeAttack {
+            limit: Duration::from_millis(250),
+        },
+        food_sequence: Some(vec![GridPosition::new(20, 20)]),
+        ..common::quiet_config(12)
+    });
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(game.step(None), StepOutcome::TimeUp);
+    assert!(game.is_finished());
+    assert_eq!(game.step(None), StepOutcome::Skipped);
+}
+
+#[test]
+fn reaching_the_target_length_reports_a_win() {
+    let mut game = Game::new(GameConfig {
+        reach_length: Some(3),
+        food_sequence: Some(vec![GridPosition::new(8, 12)]),
+        ..common::quiet_config(12)
+    });
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(game.step(None), StepOutcome::Won);
+    assert!(game.is_won());
+}
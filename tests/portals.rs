@@ -0,0 +1,54 @@
+use snake_game::{Direction, Game, GameConfig, GridPosition, StepOutcome};
+
+mod common;
+
+const ENTRY: GridPosition = GridPosition { x: 5, y: 5 };
+const EXIT: GridPosition = GridPosition { x: 15, y: 9 };
+
+fn portal_game(spawn: GridPosition, seed: u64) -> Game {
+    Game::new(GameConfig {
+        spawn: Some(spawn),
+        portals: Some((ENTRY, EXIT)),
+        ..common::quiet_config(seed)
+    })
+}
+
+#[test]
+fn entering_a_portal_exits_at_its_pair() {
+    let mut game = portal_game(GridPosition::new(4, 5), 18);
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    let player = &game.players()[0];
+    assert_eq!(player.head.position, EXIT);
+    assert_eq!(player.direction, Direction::Right);
+
+    game.step(None);
+    assert_eq!(game.players()[0].head.position, GridPosition::new(16, 9));
+}
+
+#[test]
+fn portals_work_in_both_directions() {
+    let mut game = portal_game(GridPosition::new(14, 9), 19);
+
+    game.step(None);
+
+    assert_eq!(game.players()[0].head.position, ENTRY);
+}
+
+#[test]
+fn food_never_spawns_on_a_portal() {
+    for seed in 0..200 {
+        let game = Game::new(GameConfig {
+            grid: (4, 4),
+            portals: Some((GridPosition::new(2, 1), GridPosition::new(3, 3))),
+            ..common::quiet_config(seed)
+        });
+
+        let food = game.foods()[0].position;
+        assert!(
+            food != GridPosition::new(2, 1) && food != GridPosition::new(3, 3),
+            "seed {} put food on a portal",
+            seed
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use snake_game::{Food, Game, GameConfig, GridPosition, PowerUp};
+
+mod common;
+
+fn slowed_game(power_ups: &[(i16, i16)]) -> Game {
+    let config = GameConfig {
+        frames_per_second: 8.0,
+        slow_motion_duration: Duration::from_secs(5),
+        ..common::quiet_config(6)
+    };
+
+    let mut snapshot = Game::new(config.clone()).snapshot();
+    snapshot.foods = power_ups
+        .iter()
+        .map(|&(x, y)| Food::power_up(GridPosition::new(x, y), PowerUp::SlowMotion, u64::MAX))
+        .collect();
+
+    Game::from_snapshot(config, snapshot)
+}
+
+fn slowed_time(game: &mut Game) -> Duration {
+    let mut slowed = Duration::ZERO;
+
+    game.step(None);
+    while !game.slow_motion().is_zero() {
+        slowed += game.frame_interval();
+        game.step(None);
+    }
+
+    slowed
+}
+
+fn assert_close(actual: Duration, expected: Duration) {
+    let tick = Duration::from_millis(250);
+
+    assert!(
+        actual + tick >= expected && actual <= expected + tick,
+        "slowed for {:?}, expected {:?}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn slow_motion_lasts_its_configured_duration() {
+    let mut game = slowed_game(&[(7, 12)]);
+
+    assert_close(slowed_time(&mut game), Duration::from_secs(5));
+}
+
+#[test]
+fn stacked_slow_motion_adds_the_full_duration() {
+    let mut game = slowed_game(&[(7, 12), (9, 12)]);
+
+    assert_close(slowed_time(&mut game), Duration::from_secs(10));
+}
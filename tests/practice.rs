@@ -0,0 +1,53 @@
+use snake_game::{Direction, Game, GameConfig, GameMode, GridPosition, StepOutcome, WallMode};
+
+mod common;
+
+fn coiling_game(mode: GameMode) -> Game {
+    Game::new(GameConfig {
+        mode,
+        wall_mode: WallMode::Solid,
+        initial_length: 5,
+        food_sequence: Some(vec![GridPosition::new(20, 20)]),
+        ..common::quiet_config(3)
+    })
+}
+
+fn coil(game: &mut Game) -> Vec<StepOutcome> {
+    [Direction::Down, Direction::Left, Direction::Up]
+        .into_iter()
+        .map(|direction| game.step(Some(direction)))
+        .collect()
+}
+
+#[test]
+fn self_overlap_ends_a_classic_game() {
+    let mut game = coiling_game(GameMode::Classic);
+
+    coil(&mut game);
+
+    assert!(game.is_game_over());
+}
+
+#[test]
+fn self_overlap_is_harmless_in_practice() {
+    let mut game = coiling_game(GameMode::Practice);
+
+    let outcomes = coil(&mut game);
+
+    assert!(outcomes
+        .iter()
+        .all(|outcome| *outcome == StepOutcome::Moved));
+    assert!(!game.is_game_over());
+}
+
+#[test]
+fn solid_walls_wrap_in_practice() {
+    let mut game = coiling_game(GameMode::Practice);
+    let (width, _) = game.config().grid;
+
+    for _ in 0..width {
+        game.step(None);
+    }
+
+    assert!(!game.is_game_over());
+}
@@ -0,0 +1,31 @@
+use snake_game::{Game, GameConfig, GridPosition, ObstacleLayout, WallMode};
+
+mod common;
+
+#[test]
+fn initial_food_lands_where_the_snake_can_reach_it() {
+    // A wall just right of the spawn seals the snake into a small pocket.
+    let wall: Vec<GridPosition> = (0..5).map(|y| GridPosition::new(5, y)).collect();
+
+    for seed in 0..50 {
+        let game = Game::new(GameConfig {
+            grid: (12, 5),
+            wall_mode: WallMode::Solid,
+            obstacles: ObstacleLayout::Custom(wall.clone()),
+            ..common::quiet_config(seed)
+        });
+
+        let food = game.foods()[0].position;
+        assert!(food.x < 5, "seed {} put food at {:?}", seed, food);
+    }
+}
+
+#[test]
+fn no_food_is_added_when_the_food_count_is_zero() {
+    let game = Game::new(GameConfig {
+        food_count: 0,
+        ..common::quiet_config(3)
+    });
+
+    assert!(game.foods().is_empty());
+}
@@ -0,0 +1,20 @@
+use snake_game::{GameConfig, GridPosition};
+
+#[test]
+fn positions_map_to_rectangular_cells() {
+    let rect = GridPosition::new(3, 4).to_rect((20, 10));
+
+    assert_eq!((rect.x, rect.y), (60.0, 40.0));
+    assert_eq!((rect.w, rect.h), (20.0, 10.0));
+}
+
+#[test]
+fn screen_size_follows_each_cell_axis() {
+    let config = GameConfig {
+        grid: (30, 15),
+        cell: (20, 10),
+        ..GameConfig::default()
+    };
+
+    assert_eq!(config.screen_size(), (600.0, 150.0));
+}
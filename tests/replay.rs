@@ -0,0 +1,159 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use snake_game::{Direction, Game, GameConfig, GridPosition, ObstacleLayout, Replay, WallMode};
+
+mod common;
+
+fn recorded_config() -> GameConfig {
+    GameConfig {
+        wall_mode: WallMode::Solid,
+        obstacles: ObstacleLayout::Pillars,
+        initial_length: 5,
+        growth_per_food: 3,
+        food_count: 2,
+        frames_per_second: 12.0,
+        spawn: Some(GridPosition::new(10, 12)),
+        portals: Some((GridPosition::new(1, 1), GridPosition::new(23, 23))),
+        ..common::quiet_config(21)
+    }
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("snake-replay-{}-{}", std::process::id(), name))
+}
+
+fn recorded_game() -> Game {
+    let mut game = Game::new(recorded_config());
+    let inputs = [
+        None,
+        Some(Direction::Up),
+        None,
+        Some(Direction::Left),
+        None,
+        Some(Direction::Down),
+        None,
+        None,
+    ];
+    for input in inputs {
+        game.step(input);
+    }
+
+    game
+}
+
+#[test]
+fn saved_replays_restore_the_gameplay_config() {
+    let game = recorded_game();
+    let path = temp_path("round-trip");
+
+    game.replay().save(&path).unwrap();
+    let loaded = Replay::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.seed, game.replay().seed);
+    assert_eq!(loaded.directions, game.replay().directions);
+    assert_eq!(loaded.config.obstacles, ObstacleLayout::Pillars);
+    assert_eq!(loaded.config.initial_length, 5);
+    assert_eq!(loaded.config.growth_per_food, 3);
+    assert_eq!(loaded.config.food_count, 2);
+    assert_eq!(loaded.config.frames_per_second, 12.0);
+    assert_eq!(loaded.config.spawn, Some(GridPosition::new(10, 12)));
+    assert_eq!(loaded.config.wall_mode, WallMode::Solid);
+    assert_eq!(
+        loaded.config.portals,
+        Some((GridPosition::new(1, 1), GridPosition::new(23, 23)))
+    );
+}
+
+#[test]
+fn loaded_replays_reproduce_the_run_under_a_different_config() {
+    let game = recorded_game();
+    let path = temp_path("reproduce");
+
+    game.replay().save(&path).unwrap();
+    let loaded = Replay::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let replayed = loaded.play(common::quiet_config(0)).unwrap();
+
+    assert_eq!(replayed.obstacles(), game.obstacles());
+    assert_eq!(
+        replayed.players()[0].occupied(),
+        game.players()[0].occupied()
+    );
+    assert_eq!(replayed.scores(), game.scores());
+    assert_eq!(replayed.tick(), game.tick());
+}
+
+#[test]
+fn replays_from_other_versions_are_rejected() {
+    let path = temp_path("old-version");
+    fs::write(
+        &path,
+        "snake-replay 1\nseed 3\ngrid 25 25\nwalls wrap\nRRUL\n",
+    )
+    .unwrap();
+
+    let error = Replay::load(&path).unwrap_err();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("version 1"));
+}
+
+#[test]
+fn replays_missing_a_gameplay_field_are_rejected() {
+    let game = recorded_game();
+    let path = temp_path("missing-field");
+
+    game.replay().save(&path).unwrap();
+    let contents: String = fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .filter(|line| !line.starts_with("obstacles "))
+        .map(|line| format!("{}\n", line))
+        .collect();
+    fs::write(&path, contents).unwrap();
+
+    let error = Replay::load(&path).unwrap_err();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("obstacles"));
+}
+
+#[test]
+fn replays_with_an_unplayable_header_are_rejected() {
+    let game = recorded_game();
+    let saved = temp_path("bad-header");
+    game.replay().save(&saved).unwrap();
+    let contents = fs::read_to_string(&saved).unwrap();
+    fs::remove_file(&saved).unwrap();
+
+    for (field, bad) in [("fps", "fps 0"), ("grid", "grid 0 0")] {
+        let path = temp_path(&format!("bad-{}", field));
+        let edited: String = contents
+            .lines()
+            .map(|line| {
+                if line.starts_with(&format!("{} ", field)) {
+                    format!("{}\n", bad)
+                } else {
+                    format!("{}\n", line)
+                }
+            })
+            .collect();
+        fs::write(&path, edited).unwrap();
+
+        let error = Replay::load(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{}", bad);
+        assert!(
+            error.to_string().contains("invalid replay config"),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use snake_game::{Game, GameConfig, GridPosition, WallMode};
+
+mod common;
+
+#[test]
+fn a_two_player_snapshot_restores_under_a_one_player_config() {
+    let two_players = GameConfig {
+        grid: (20, 12),
+        wall_mode: WallMode::Wrap,
+        two_player: true,
+        hunger_interval: Some(Duration::from_secs(10)),
+        food_sequence: None,
+        ..common::quiet_config(13)
+    };
+    let mut game = Game::new(two_players.clone());
+    for _ in 0..3 {
+        game.step(None);
+    }
+
+    let mut restored = Game::from_snapshot(
+        GameConfig {
+            two_player: false,
+            ..two_players
+        },
+        game.snapshot(),
+    );
+    assert_eq!(restored.players().len(), 2);
+    assert_eq!(restored.elapsed(), game.elapsed());
+
+    for _ in 0..5 {
+        assert_eq!(restored.step(None), game.step(None));
+    }
+    let foods = |game: &Game| -> Vec<GridPosition> {
+        game.foods().iter().map(|food| food.position).collect()
+    };
+    assert_eq!(foods(&restored), foods(&game));
+    assert_eq!(restored.elapsed(), game.elapsed());
+    assert_eq!(restored.scores(), game.scores());
+}
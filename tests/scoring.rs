@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use snake_game::{Game, GameConfig, GridPosition, StepOutcome, WallMode};
+
+mod common;
+
+fn scored(scoring: fn(usize, Duration) -> u32) -> Game {
+    Game::new(GameConfig {
+        grid: (10, 10),
+        wall_mode: WallMode::Solid,
+        food_sequence: Some(vec![GridPosition::new(5, 5), GridPosition::new(8, 5)]),
+        cycle_food_sequence: false,
+        speed_up_per_food: Duration::ZERO,
+        scoring,
+        ..common::quiet_config(20)
+    })
+}
+
+fn eat_twice(game: &mut Game) -> Vec<u32> {
+    let mut scores = Vec::new();
+
+    for _ in 0..6 {
+        if game.step(None) == StepOutcome::Ate {
+            scores.push(game.score());
+        }
+    }
+
+    scores
+}
+
+#[test]
+fn the_default_rule_scores_a_point_per_food() {
+    let mut game = scored(snake_game::flat_scoring);
+
+    assert_eq!(eat_twice(&mut game), vec![1, 2]);
+}
+
+#[test]
+fn a_custom_rule_can_reward_length() {
+    let mut game = scored(|length, _| length as u32 * 10);
+
+    // The snake has already grown by the time the food is scored.
+    assert_eq!(eat_twice(&mut game), vec![30, 70]);
+}
+
+#[test]
+fn a_custom_rule_can_reward_quick_meals() {
+    let mut game = scored(|_, since_last_food| 10 - since_last_food.as_millis() as u32 / 125);
+
+    // Three ticks to the first food, then three more to the second.
+    assert_eq!(eat_twice(&mut game), vec![7, 14]);
+}
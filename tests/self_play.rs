@@ -0,0 +1,79 @@
+use snake_game::{AiStrategy, Game, GameConfig, StepOutcome, WallMode};
+
+mod common;
+
+const MAX_TICKS: usize = 5_000;
+
+fn bot_game(seed: u64, wall_mode: WallMode) -> Game {
+    Game::new(GameConfig {
+        grid: (6, 6),
+        wall_mode,
+        ai_mode: true,
+        ai_strategy: AiStrategy::Bfs,
+        undo_depth: 1,
+        ..common::quiet_config(seed)
+    })
+}
+
+fn play_out(mut game: Game) {
+    for _ in 0..MAX_TICKS {
+        let outcome = game.step(None);
+
+        for food in game.foods() {
+            assert!(
+                !game
+                    .players()
+                    .iter()
+                    .any(|player| player.occupies(food.position)),
+                "food spawned on the snake at tick {}",
+                game.tick()
+            );
+        }
+
+        match outcome {
+            StepOutcome::Moved | StepOutcome::Ate => {}
+            StepOutcome::Won => return,
+            StepOutcome::Died(cause) => {
+                assert!(game.undo());
+
+                let config = game.config();
+                let player = &game.players()[0];
+                let choice = config.ai_strategy.choose(
+                    player,
+                    game.foods(),
+                    game.obstacles(),
+                    config.grid,
+                    config.wall_mode,
+                );
+
+                assert_eq!(
+                    choice,
+                    None,
+                    "died of {:?} at tick {} with a safe move left",
+                    cause,
+                    game.tick()
+                );
+                return;
+            }
+            StepOutcome::TimeUp | StepOutcome::Skipped => {
+                panic!("unexpected {:?} at tick {}", outcome, game.tick())
+            }
+        }
+    }
+
+    panic!("game neither won nor lost within {} ticks", MAX_TICKS);
+}
+
+#[test]
+fn bfs_bot_only_dies_when_trapped_in_solid_walls() {
+    for seed in 0..20 {
+        play_out(bot_game(seed, WallMode::Solid));
+    }
+}
+
+#[test]
+fn bfs_bot_only_dies_when_trapped_when_wrapping() {
+    for seed in 0..20 {
+        play_out(bot_game(seed, WallMode::Wrap));
+    }
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use snake_game::{Food, FoodKind, Game, GameConfig, GridPosition, WallMode};
+
+mod common;
+
+const GRID: (i16, i16) = (8, 8);
+
+fn shrinking_game(seed: u64) -> Game {
+    Game::new(GameConfig {
+        grid: GRID,
+        frames_per_second: 10.0,
+        wall_mode: WallMode::Solid,
+        shrink_interval: Some(Duration::from_millis(100)),
+        ..common::quiet_config(seed)
+    })
+}
+
+fn outer_ring() -> Vec<GridPosition> {
+    (0..GRID.1)
+        .flat_map(|y| (0..GRID.0).map(move |x| GridPosition::new(x, y)))
+        .filter(|position| {
+            position.x == 0
+                || position.y == 0
+                || position.x == GRID.0 - 1
+                || position.y == GRID.1 - 1
+        })
+        .collect()
+}
+
+#[test]
+fn one_shrink_walls_the_outer_ring() {
+    let mut game = shrinking_game(5);
+
+    game.step(None);
+
+    assert_eq!(game.shrinks(), 1);
+    for position in outer_ring() {
+        assert!(game.obstacles().contains(&position), "{:?}", position);
+    }
+}
+
+#[test]
+fn food_never_spawns_on_the_walled_ring() {
+    for seed in 0..50 {
+        let mut game = shrinking_game(seed);
+
+        game.step(None);
+
+        for food in game.foods() {
+            assert!(!outer_ring().contains(&food.position), "seed {}", seed);
+        }
+    }
+}
+
+#[test]
+fn buried_food_keeps_its_kind() {
+    let config = GameConfig {
+        grid: GRID,
+        frames_per_second: 10.0,
+        wall_mode: WallMode::Solid,
+        shrink_interval: Some(Duration::from_millis(100)),
+        ..common::quiet_config(27)
+    };
+    let mut snapshot = Game::new(config.clone()).snapshot();
+    snapshot.foods = vec![
+        Food::new(GridPosition::new(4, 2)),
+        Food::bonus(GridPosition::new(0, 6), 50),
+    ];
+    let mut game = Game::from_snapshot(config, snapshot);
+
+    game.step(None);
+    assert_eq!(game.shrinks(), 1);
+
+    let mut foods: Vec<(FoodKind, Option<u64>)> = game
+        .foods()
+        .iter()
+        .map(|food| (food.kind, food.expires_at))
+        .collect();
+    foods.sort_by_key(|(_, expires_at)| *expires_at);
+    assert_eq!(
+        foods,
+        vec![(FoodKind::Normal, None), (FoodKind::Bonus, Some(50))]
+    );
+    for food in game.foods() {
+        assert!(!outer_ring().contains(&food.position), "{:?}", food);
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "serde")]
+
+use snake_game::{Direction, Game, GameConfig, GameSnapshot, GridPosition, Player};
+
+mod common;
+
+fn config() -> GameConfig {
+    GameConfig {
+        initial_length: 4,
+        food_sequence: Some(vec![GridPosition::new(8, 12), GridPosition::new(8, 9)]),
+        ..common::quiet_config(13)
+    }
+}
+
+fn body(player: &Player) -> Vec<GridPosition> {
+    player
+        .body()
+        .iter()
+        .map(|segment| segment.position)
+        .collect()
+}
+
+#[test]
+fn a_mid_game_snapshot_round_trips_through_json() {
+    let mut game = Game::new(config());
+    game.step(None);
+    game.step(None);
+    game.turn(Direction::Up);
+    game.step(None);
+    assert_eq!(game.score(), 1);
+
+    let json = serde_json::to_string(&game.snapshot()).unwrap();
+    let snapshot: GameSnapshot = serde_json::from_str(&json).unwrap();
+    let restored = Game::from_snapshot(config(), snapshot);
+
+    let (original, player) = (&game.players()[0], &restored.players()[0]);
+    assert_eq!(player.head.position, original.head.position);
+    assert_eq!(body(player), body(original));
+    assert_eq!(player.direction, original.direction);
+    assert_eq!(player.occupied(), original.occupied());
+    assert_eq!(player.occupied().len(), player.body().len());
+
+    let foods = |game: &Game| -> Vec<GridPosition> {
+        game.foods().iter().map(|food| food.position).collect()
+    };
+    assert_eq!(foods(&restored), foods(&game));
+    assert_eq!(restored.scores(), game.scores());
+    assert_eq!(restored.tick(), game.tick());
+    assert_eq!(restored.frame_interval(), game.frame_interval());
+    assert_eq!(restored.elapsed(), game.elapsed());
+}
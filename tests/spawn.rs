@@ -0,0 +1,90 @@
+use snake_game::{Direction, Game, GameConfig, GridPosition, ObstacleLayout, Player};
+
+mod common;
+
+fn body(player: &Player) -> Vec<GridPosition> {
+    player
+        .body()
+        .iter()
+        .map(|segment| segment.position)
+        .collect()
+}
+
+#[test]
+fn a_length_five_snake_trails_behind_its_head() {
+    let game = Game::new(GameConfig {
+        initial_length: 5,
+        ..common::quiet_config(9)
+    });
+    let player = &game.players()[0];
+
+    assert_eq!(player.length(), 5);
+    assert_eq!(player.head.position, GridPosition::new(6, 12));
+    assert_eq!(
+        body(player),
+        vec![
+            GridPosition::new(5, 12),
+            GridPosition::new(4, 12),
+            GridPosition::new(3, 12),
+            GridPosition::new(2, 12),
+        ]
+    );
+}
+
+#[test]
+fn the_starting_length_is_clamped_to_the_grid() {
+    let player = Player::spawn(GridPosition::new(2, 0), Direction::Right, 5, (10, 1));
+
+    assert_eq!(player.length(), 3);
+    assert_eq!(
+        body(&player),
+        vec![GridPosition::new(1, 0), GridPosition::new(0, 0)]
+    );
+}
+
+#[test]
+fn a_custom_spawn_can_head_up() {
+    let config = GameConfig {
+        spawn: Some(GridPosition::new(3, 4)),
+        spawn_direction: Direction::Up,
+        initial_length: 3,
+        ..common::quiet_config(24)
+    };
+    assert_eq!(config.validate(), Ok(()));
+
+    let mut game = Game::new(config);
+    let player = &game.players()[0];
+    assert_eq!(player.head.position, GridPosition::new(3, 4));
+    assert_eq!(player.direction, Direction::Up);
+    assert_eq!(
+        body(player),
+        vec![GridPosition::new(3, 5), GridPosition::new(3, 6)]
+    );
+
+    game.step(None);
+    assert_eq!(game.players()[0].head.position, GridPosition::new(3, 3));
+}
+
+#[test]
+fn spawns_that_do_not_fit_are_rejected() {
+    let outside = GameConfig {
+        spawn: Some(GridPosition::new(25, 4)),
+        ..GameConfig::default()
+    };
+    let too_long = GameConfig {
+        spawn: Some(GridPosition::new(3, 23)),
+        spawn_direction: Direction::Up,
+        initial_length: 4,
+        ..GameConfig::default()
+    };
+    let on_an_obstacle = GameConfig {
+        spawn: Some(GridPosition::new(3, 5)),
+        spawn_direction: Direction::Up,
+        obstacles: ObstacleLayout::Custom(vec![GridPosition::new(3, 6)]),
+        ..GameConfig::default()
+    };
+
+    assert!(outside.validate().is_err());
+    assert!(too_long.validate().is_err());
+    assert!(on_an_obstacle.validate().is_err());
+}
@@ -0,0 +1,162 @@
+use snake_game::{Collision, Direction, Game, GameConfig, GridPosition, StepOutcome, WallMode};
+
+mod common;
+
+fn scripted_config() -> GameConfig {
+    GameConfig {
+        grid: (10, 10),
+        wall_mode: WallMode::Solid,
+        food_sequence: Some(vec![GridPosition::new(5, 5), GridPosition::new(5, 2)]),
+        cycle_food_sequence: false,
+        undo_depth: 0,
+        ..common::quiet_config(7)
+    }
+}
+
+#[test]
+fn scripted_run_follows_expected_trajectory() {
+    let mut game = Game::new(scripted_config());
+    assert_eq!(game.players()[0].head.position, GridPosition::new(2, 5));
+
+    let inputs = [
+        None,
+        None,
+        None,
+        Some(Direction::Up),
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+    let expected = [
+        ((3, 5), StepOutcome::Moved, 0),
+        ((4, 5), StepOutcome::Moved, 0),
+        ((5, 5), StepOutcome::Ate, 1),
+        ((5, 4), StepOutcome::Moved, 1),
+        ((5, 3), StepOutcome::Moved, 1),
+        ((5, 2), StepOutcome::Ate, 2),
+        ((5, 1), StepOutcome::Moved, 2),
+        ((5, 0), StepOutcome::Moved, 2),
+        ((5, 0), StepOutcome::Died(Collision::Wall), 2),
+    ];
+
+    for (tick, (input, (head, outcome, score))) in inputs.iter().zip(expected).enumerate() {
+        assert_eq!(game.step(*input), outcome, "outcome at tick {}", tick);
+        assert_eq!(
+            game.players()[0].head.position,
+            GridPosition::new(head.0, head.1),
+            "head at tick {}",
+            tick
+        );
+        assert_eq!(game.score(), score, "score at tick {}", tick);
+    }
+
+    assert!(game.is_game_over());
+    assert_eq!(game.players()[0].length(), 4);
+    assert_eq!(game.step(None), StepOutcome::Skipped);
+}
+
+#[test]
+fn eaten_food_respawns_from_the_script() {
+    let mut game = Game::new(scripted_config());
+
+    for _ in 0..3 {
+        game.step(None);
+    }
+
+    let foods: Vec<GridPosition> = game.foods().iter().map(|food| food.position).collect();
+    assert_eq!(foods, vec![GridPosition::new(5, 2)]);
+}
+
+#[test]
+fn replaying_the_recorded_inputs_reproduces_the_run() {
+    let mut game = Game::new(scripted_config());
+    game.step(None);
+    game.step(Some(Direction::Down));
+    game.step(Some(Direction::Left));
+    game.step(None);
+
+    let replayed = game.replay().play(scripted_config()).unwrap();
+
+    assert_eq!(
+        replayed.players()[0].head.position,
+        game.players()[0].head.position
+    );
+    assert_eq!(replayed.scores(), game.scores());
+    assert_eq!(replayed.tick(), game.tick());
+}
+
+#[test]
+fn scripted_foods_appear_exactly_in_order() {
+    let script = vec![
+        GridPosition::new(4, 5),
+        GridPosition::new(6, 5),
+        GridPosition::new(6, 7),
+        GridPosition::new(1, 7),
+    ];
+    let mut game = Game::new(GameConfig {
+        food_sequence: Some(script.clone()),
+        ..scripted_config()
+    });
+    let inputs = [
+        None,
+        None,
+        None,
+        None,
+        Some(Direction::Down),
+        None,
+        Some(Direction::Left),
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    let mut seen = vec![game.foods()[0].position];
+    for input in inputs {
+        game.step(input);
+        if let Some(food) = game.foods().first() {
+            if seen.last() != Some(&food.position) {
+                seen.push(food.position);
+            }
+        }
+    }
+
+    assert_eq!(seen, script);
+    assert_eq!(game.score(), 4);
+}
+
+#[test]
+fn scripted_foods_skip_cells_under_the_snake() {
+    let mut game = Game::new(GameConfig {
+        food_sequence: Some(vec![
+            GridPosition::new(4, 5),
+            GridPosition::new(3, 5),
+            GridPosition::new(8, 5),
+        ]),
+        ..scripted_config()
+    });
+
+    game.step(None);
+    game.step(None);
+
+    let foods: Vec<GridPosition> = game.foods().iter().map(|food| food.position).collect();
+    assert_eq!(foods, vec![GridPosition::new(8, 5)]);
+}
+
+#[test]
+fn a_single_cycled_entry_never_lands_under_the_head() {
+    let mut game = Game::new(GameConfig {
+        food_sequence: Some(vec![GridPosition::new(4, 5)]),
+        cycle_food_sequence: true,
+        ..scripted_config()
+    });
+
+    game.step(None);
+    game.step(None);
+
+    let head = game.players()[0].head.position;
+    assert_eq!(head, GridPosition::new(4, 5));
+    assert!(game.foods().iter().all(|food| food.position != head));
+}
@@ -0,0 +1,48 @@
+use snake_game::{Direction, Game, GameConfig, GridPosition, StepOutcome};
+
+mod common;
+
+fn game() -> Game {
+    Game::new(GameConfig {
+        spawn: Some(GridPosition::new(6, 6)),
+        initial_length: 4,
+        ..common::quiet_config(4)
+    })
+}
+
+#[test]
+fn two_quick_turns_cannot_reverse_into_the_body() {
+    let mut game = game();
+
+    game.turn(Direction::Up);
+    game.turn(Direction::Left);
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(game.players()[0].head.position, GridPosition::new(6, 5));
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(game.players()[0].head.position, GridPosition::new(5, 5));
+}
+
+#[test]
+fn turns_are_checked_against_the_pending_heading() {
+    let mut game = game();
+
+    game.turn(Direction::Up);
+    game.turn(Direction::Down);
+
+    assert_eq!(
+        game.players()[0].queued_turns,
+        vec![Direction::Up],
+        "reversing the pending turn was queued"
+    );
+}
+
+#[test]
+fn reversing_the_current_heading_is_ignored() {
+    let mut game = game();
+
+    game.turn(Direction::Left);
+
+    assert_eq!(game.step(None), StepOutcome::Moved);
+    assert_eq!(game.players()[0].head.position, GridPosition::new(7, 6));
+}
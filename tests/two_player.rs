@@ -0,0 +1,114 @@
+use snake_game::{
+    Collision, CollisionRules, Direction, Game, GameConfig, GridPosition, HeadOn, StepOutcome,
+    WallMode,
+};
+
+mod common;
+
+fn duel(grid: (i16, i16), head_on: HeadOn) -> Game {
+    Game::new(GameConfig {
+        grid,
+        wall_mode: WallMode::Solid,
+        two_player: true,
+        initial_length: 4,
+        food_sequence: Some(vec![GridPosition::new(0, grid.1 - 1)]),
+        collision_rules: CollisionRules {
+            head_on,
+            ..CollisionRules::default()
+        },
+        ..common::quiet_config(1)
+    })
+}
+
+#[test]
+fn head_into_body_kills_only_the_mover() {
+    let mut game = duel((12, 7), HeadOn::Draw);
+
+    game.turn_player(0, Direction::Up);
+    game.step(None);
+    game.turn_player(0, Direction::Right);
+    game.step(None);
+    game.step(None);
+    game.turn_player(0, Direction::Down);
+
+    assert_eq!(game.step(None), StepOutcome::Died(Collision::Opponent));
+    assert_eq!(game.players()[0].collision, Some(Collision::Opponent));
+    assert_ne!(game.players()[1].collision, Some(Collision::Opponent));
+    assert_eq!(game.winner(), Some(1));
+}
+
+#[test]
+fn meeting_head_on_is_a_draw() {
+    let mut game = duel((13, 5), HeadOn::Draw);
+
+    while !game.is_finished() {
+        game.step(None);
+    }
+
+    assert_eq!(game.players()[0].head.position, GridPosition::new(6, 2));
+    assert_eq!(game.players()[1].head.position, GridPosition::new(6, 2));
+    assert_eq!(game.winner(), None);
+}
+
+#[test]
+fn swapping_heads_counts_as_meeting() {
+    let mut game = duel((14, 5), HeadOn::Draw);
+
+    while !game.is_finished() {
+        game.step(None);
+    }
+
+    assert_eq!(game.players()[0].collision, Some(Collision::Opponent));
+    assert_eq!(game.players()[1].collision, Some(Collision::Opponent));
+    assert_eq!(game.winner(), None);
+}
+
+#[test]
+fn longer_snake_wins_head_on() {
+    let mut game = Game::new(GameConfig {
+        initial_length: 2,
+        growth_per_food: 3,
+        food_sequence: Some(vec![GridPosition::new(4, 2), GridPosition::new(0, 0)]),
+        cycle_food_sequence: false,
+        ..duel((13, 5), HeadOn::Longest).config().clone()
+    });
+
+    while !game.is_finished() {
+        game.step(None);
+    }
+
+    assert!(game.players()[0].length() > game.players()[1].length());
+    assert_eq!(game.players()[1].collision, Some(Collision::Opponent));
+    assert_eq!(game.winner(), Some(0));
+}
+
+#[test]
+fn replays_reproduce_both_players_inputs() {
+    let config = || GameConfig {
+        grid: (16, 9),
+        wall_mode: WallMode::Solid,
+        two_player: true,
+        food_sequence: Some(vec![GridPosition::new(3, 2), GridPosition::new(12, 6)]),
+        ..common::quiet_config(1)
+    };
+    let mut game = Game::new(config());
+
+    game.turn_player(0, Direction::Up);
+    game.turn_player(1, Direction::Down);
+    game.step(None);
+    game.turn_player(1, Direction::Left);
+    game.step(None);
+    game.step(None);
+    game.turn_player(0, Direction::Left);
+    game.turn_player(1, Direction::Down);
+    game.step(None);
+
+    let replayed = game.replay().play(config()).unwrap();
+
+    for (replayed, original) in replayed.players().iter().zip(game.players()) {
+        assert_eq!(replayed.head.position, original.head.position);
+        assert_eq!(replayed.direction, original.direction);
+    }
+    assert_eq!(replayed.scores(), game.scores());
+    assert_eq!(replayed.tick(), game.tick());
+}
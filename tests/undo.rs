@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use snake_game::{Direction, Game, GameConfig, GridPosition};
+
+mod common;
+
+#[derive(Debug, PartialEq)]
+struct State {
+    head: GridPosition,
+    body: Vec<GridPosition>,
+    direction: Direction,
+    foods: Vec<GridPosition>,
+    scores: Vec<u32>,
+    tick: u64,
+    elapsed: Duration,
+}
+
+fn state(game: &Game) -> State {
+    let player = &game.players()[0];
+
+    State {
+        head: player.head.position,
+        body: player
+            .body()
+            .iter()
+            .map(|segment| segment.position)
+            .collect(),
+        direction: player.direction,
+        foods: game.foods().iter().map(|food| food.position).collect(),
+        scores: game.scores().to_vec(),
+        tick: game.tick(),
+        elapsed: game.elapsed(),
+    }
+}
+
+fn game() -> Game {
+    Game::new(GameConfig {
+        undo_depth: 8,
+        ..common::quiet_config(22)
+    })
+}
+
+#[test]
+fn undoing_a_step_restores_the_previous_state() {
+    let mut game = game();
+    game.step(None);
+    let one_step = state(&game);
+
+    game.turn(Direction::Down);
+    game.step(None);
+    assert_ne!(state(&game), one_step);
+
+    assert!(game.undo());
+    assert_eq!(state(&game), one_step);
+}
+
+#[test]
+fn undoing_a_meal_restores_the_food_and_score() {
+    let mut game = Game::new(GameConfig {
+        food_sequence: Some(vec![GridPosition::new(8, 12), GridPosition::new(3, 3)]),
+        ..game().config().clone()
+    });
+    game.step(None);
+    let one_step = state(&game);
+
+    game.step(None);
+    assert_eq!(game.score(), 1);
+    let two_steps = state(&game);
+
+    assert!(game.undo());
+    assert_eq!(state(&game), one_step);
+
+    game.step(None);
+    assert_eq!(state(&game), two_steps);
+}
+
+#[test]
+fn undo_stops_at_the_start() {
+    let mut game = game();
+    game.step(None);
+
+    assert!(game.undo());
+    assert!(!game.undo());
+    assert_eq!(game.tick(), 0);
+}
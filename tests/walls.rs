@@ -0,0 +1,55 @@
+use snake_game::{Collision, Direction, Game, GameConfig, GridPosition, StepOutcome, WallMode};
+
+mod common;
+
+type Cell = (i16, i16);
+
+// Heading, spawn at the edge, and where wrapping lands.
+const EDGES: [(Direction, Cell, Cell); 4] = [
+    (Direction::Up, (5, 0), (5, 9)),
+    (Direction::Down, (5, 9), (5, 0)),
+    (Direction::Left, (0, 5), (9, 5)),
+    (Direction::Right, (9, 5), (0, 5)),
+];
+
+fn heading_off_the_edge(wall_mode: WallMode, direction: Direction, spawn: Cell) -> Game {
+    Game::new(GameConfig {
+        grid: (10, 10),
+        wall_mode,
+        spawn: Some(spawn.into()),
+        spawn_direction: direction,
+        food_sequence: Some(vec![GridPosition::new(3, 3)]),
+        ..common::quiet_config(6)
+    })
+}
+
+#[test]
+fn solid_walls_end_the_game_at_every_edge() {
+    for (direction, spawn, _) in EDGES {
+        let mut game = heading_off_the_edge(WallMode::Solid, direction, spawn);
+
+        assert_eq!(
+            game.step(None),
+            StepOutcome::Died(Collision::Wall),
+            "heading {:?}",
+            direction
+        );
+        assert!(game.is_game_over());
+        assert_eq!(game.players()[0].head.position, spawn.into());
+    }
+}
+
+#[test]
+fn wrapping_crosses_every_edge() {
+    for (direction, spawn, wrapped) in EDGES {
+        let mut game = heading_off_the_edge(WallMode::Wrap, direction, spawn);
+
+        assert_eq!(
+            game.step(None),
+            StepOutcome::Moved,
+            "heading {:?}",
+            direction
+        );
+        assert_eq!(game.players()[0].head.position, wrapped.into());
+    }
+}
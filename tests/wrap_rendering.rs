@@ -0,0 +1,51 @@
+use snake_game::GridPosition;
+
+const CELL: (i16, i16) = (10, 10);
+const GRID: (i16, i16) = (5, 5);
+
+fn wrapping_segment(progress: f32, wrap_duration: f32) -> ([f32; 2], Option<[f32; 2]>) {
+    GridPosition::new(4, 2).render_positions(
+        GridPosition::new(0, 2),
+        CELL,
+        GRID,
+        progress,
+        wrap_duration,
+    )
+}
+
+#[test]
+fn without_interpolation_a_wrap_is_drawn_on_one_side() {
+    for wrap_duration in [0.0, 0.5, 1.0] {
+        assert_eq!(wrapping_segment(0.0, wrap_duration), ([40.0, 20.0], None));
+    }
+}
+
+#[test]
+fn full_duration_straddles_both_edges_mid_tick() {
+    assert_eq!(
+        wrapping_segment(0.5, 1.0),
+        ([45.0, 20.0], Some([-5.0, 20.0]))
+    );
+}
+
+#[test]
+fn short_duration_waits_before_crossing() {
+    assert_eq!(wrapping_segment(0.5, 0.25), ([40.0, 20.0], None));
+    assert_eq!(
+        wrapping_segment(0.875, 0.25),
+        ([45.0, 20.0], Some([-5.0, 20.0]))
+    );
+}
+
+#[test]
+fn zero_duration_teleports_instantly() {
+    assert_eq!(wrapping_segment(0.99, 0.0), ([40.0, 20.0], None));
+}
+
+#[test]
+fn ordinary_moves_ignore_the_wrap_duration() {
+    let position =
+        GridPosition::new(1, 1).render_positions(GridPosition::new(2, 1), CELL, GRID, 0.5, 0.0);
+
+    assert_eq!(position, ([15.0, 10.0], None));
+}